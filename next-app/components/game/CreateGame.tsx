@@ -44,7 +44,7 @@ export default function CreateGame({ onCreateGame }: CreateGameProps) {
       const gameId: number = await fetchCurrentGameId();
       const { 
         transaction, latestBlockhash, totalCost, 
-        gamePda, playerPda, bidPda 
+        gamePda, playerPda, ledgerPda 
       } = await createGame(publicKey, gameId, bidAmount)
        
       const balance = await CONNECTION.getBalance(publicKey)
@@ -76,7 +76,7 @@ export default function CreateGame({ onCreateGame }: CreateGameProps) {
         creatorPublicKey: publicKey.toString(),
         gamePda: gamePda.toString(),
         playerPda: playerPda.toString(),
-        bidPda: bidPda.toString(),
+        bidPda: ledgerPda.toString(),
       };
 
       const res = await axios.post("/api/game", gameData);
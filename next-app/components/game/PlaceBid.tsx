@@ -13,6 +13,7 @@ import { GameData } from '@/types/game'
 import { useWallet } from '@solana/wallet-adapter-react';
 import { CONNECTION } from '@/lib/constant';
 import { getAllPlayersAndBidsForGame, getGameData } from '@/solana/game';
+import { GameStatus } from '@/solana/state';
 
 interface PlaceBidModalProps {
   gameId: number
@@ -73,7 +74,7 @@ export default function PlaceBid({ gameId, bidCount, currBid, onPlaceBid }: Plac
       const { 
         transaction, 
         latestBlockhash, totalCost, 
-        playerPda, bidPda 
+        playerPda, ledgerPda 
       } = await placeBid(publicKey, gameId, bidAmountNumber,  Number(bidCount) + 1)
       
       const balance = await CONNECTION.getBalance(publicKey)
@@ -97,13 +98,13 @@ export default function PlaceBid({ gameId, bidCount, currBid, onPlaceBid }: Plac
       }
 
       const game  = await getGameData(gameId)
-      if(game?.game_ended){
+      if(game && game.status !== GameStatus.Active){
         const playerData = await getAllPlayersAndBidsForGame(gameId);
         const res = await axios.put("/api/bid", {
           gameId: gameId,
           creatorPublicKey: publicKey.toString(),
           playerPda: playerPda.toString(),
-          bidPda: bidPda.toString(),
+          bidPda: ledgerPda.toString(),
           playerData: playerData,
           amount: bidAmountNumber,
           bidCount: Number(bidCount) + 1
@@ -123,7 +124,7 @@ export default function PlaceBid({ gameId, bidCount, currBid, onPlaceBid }: Plac
           amount: bidAmountNumber,
           creatorPublicKey: publicKey.toString(),
           playerPda: playerPda.toString(),
-          bidPda: bidPda.toString(),
+          bidPda: ledgerPda.toString(),
           bidCount: Number(bidCount) + 1,
         });
         if(res.status === 200){
@@ -7,16 +7,16 @@ import {
 } from '@solana/web3.js';
 import BN from 'bn.js';
 import {convertUsdcToLamports } from '@/lib/helper';
-import axios from 'axios';
 import { PROGRAM_ID, CONNECTION, PLATFROM_ACCOUNT } from '@/lib/constant';
-import { getBidPda, getGamePda, getPlayerPda } from './pda';
+import { getGamePda, getLedgerPda, getPlayerPda, getStatsPda, getVaultPda } from './pda';
 
 
-//work only for 14 bids
 export const placeBid = async (bidder: PublicKey, gameId: number, bidAmount: number, bidCount: number) => {
   const gamePda = getGamePda(new BN(gameId));
   const playerPda = getPlayerPda(new BN(gameId), bidder, new BN(bidCount));
-  const bidPda = getBidPda(new BN(gameId), new BN(bidCount));
+  const ledgerPda = getLedgerPda(new BN(gameId));
+  const statsPda = getStatsPda(new BN(gameId), bidder);
+  const vaultPda = getVaultPda(new BN(gameId));
   const bidAmountLamports = convertUsdcToLamports(bidAmount);
   const bidAmountLamportsBuffer = Buffer.alloc(8);
   bidAmountLamportsBuffer.writeBigUInt64LE(BigInt(bidAmountLamports));
@@ -34,32 +34,12 @@ export const placeBid = async (bidder: PublicKey, gameId: number, bidAmount: num
     { pubkey: PLATFROM_ACCOUNT, isSigner: false, isWritable: true},
     { pubkey: gamePda, isSigner: false, isWritable: true },
     { pubkey: bidder, isSigner: true, isWritable: true },
-    { pubkey: bidPda, isSigner: false, isWritable: true },
+    { pubkey: ledgerPda, isSigner: false, isWritable: true },
     { pubkey: playerPda, isSigner: false, isWritable: true },
+    { pubkey: vaultPda, isSigner: false, isWritable: true },
+    { pubkey: statsPda, isSigner: false, isWritable: true },
   ];
 
-  const res = await axios.get('/api/bid', {
-    params: {
-      id: gameId,
-    },
-  });
-   
-  const playersPda = res.data.playersPda;  
-  const bidsPda = res.data.bidsPda; 
-  const playersPubkeys = res.data.playersPubkey;
-
-  playersPda.forEach((player:string) => {
-    keys.push({ pubkey: new PublicKey(player), isSigner: false, isWritable: true });  
-  });
-
-  bidsPda.forEach((bid:string) => {
-    keys.push({ pubkey: new PublicKey(bid), isSigner: false, isWritable: true }); 
-  });
-
-  playersPubkeys.forEach((playerPubkey:string) => {
-    keys.push({ pubkey: new PublicKey(playerPubkey), isSigner: false, isWritable: true });  
-  });
-
   const placeBidIx = new TransactionInstruction({
     keys,
     programId: PROGRAM_ID,
@@ -69,8 +49,6 @@ export const placeBid = async (bidder: PublicKey, gameId: number, bidAmount: num
   const latestBlockhash = await CONNECTION.getLatestBlockhash('confirmed');
 
   const transaction = new Transaction();
-  console.log("bidder", bidder.toString())
-  transaction.feePayer = bidder
   transaction.add(placeBidIx);
   transaction.recentBlockhash = latestBlockhash.blockhash;
   transaction.feePayer = bidder;
@@ -79,109 +57,9 @@ export const placeBid = async (bidder: PublicKey, gameId: number, bidAmount: num
   if (fees === null) {
     fees = 50000000 
   }
-  const totalCost = fees + bidAmount;
-  return { transaction, latestBlockhash, totalCost, playerPda, bidPda };
+  const totalCost = fees + bidAmountLamports;
+  return { transaction, latestBlockhash, totalCost, playerPda, ledgerPda };
 }
-
-
-export const placeBidScale = async (bidder: PublicKey, gameId: number, bidAmount: number, bidCount: number) => {
-  const gamePda = getGamePda(new BN(gameId));
-  const playerPda = getPlayerPda(new BN(gameId), bidder, new BN(bidCount));
-  const bidPda = getBidPda(new BN(gameId), new BN(bidCount));
-  
-  const bidAmountLamports = convertUsdcToLamports(bidAmount);
-  const bidAmountLamportsBuffer = Buffer.alloc(8);
-  bidAmountLamportsBuffer.writeBigUInt64LE(BigInt(bidAmountLamports));
-  const bidCountBuffer = Buffer.alloc(8);
-  bidCountBuffer.writeBigUInt64LE(BigInt(bidCount));
-  
-  const instructionData = Buffer.concat([
-    Buffer.from([1]), 
-    bidAmountLamportsBuffer,
-    bidCountBuffer
-  ]);
-
-  const res = await axios.get('/api/bid', {
-    params: { id: gameId },
-  });
-
-  const baseKeys = [
-    { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
-    { pubkey: PLATFROM_ACCOUNT, isSigner: false, isWritable: true },
-    { pubkey: gamePda, isSigner: false, isWritable: true },
-    { pubkey: bidder, isSigner: true, isWritable: true },
-    { pubkey: bidPda, isSigner: false, isWritable: true },
-    { pubkey: playerPda, isSigner: false, isWritable: true },
-  ];
-
-  const playersPda = res.data.playersPda.map((p: string) => new PublicKey(p));
-  const bidsPda = res.data.bidsPda.map((b: string) => new PublicKey(b));
-  const playersPubkeys = res.data.playersPubkey.map((p: string) => new PublicKey(p));
-
-  const CHUNK_SIZE = 12;
- 
-  const chunkedPlayersPda = [];
-  const chunkedBidsPda = [];
-  const chunkedPlayersPubkeys = [];
- 
-  for (let i = 0; i < playersPda.length; i += CHUNK_SIZE) {
-    chunkedPlayersPda.push(playersPda.slice(i, i + CHUNK_SIZE));
-    chunkedBidsPda.push(bidsPda.slice(i, i + CHUNK_SIZE));
-    chunkedPlayersPubkeys.push(playersPubkeys.slice(i, i + CHUNK_SIZE));
-  }
- 
-  const instructions: TransactionInstruction[] = [];
-
-  for (let i = 0; i < chunkedPlayersPda.length; i++) {
-    const chunkKeys = [
-      ...baseKeys,
-      ...chunkedPlayersPda[i].map( ({ pubkey }: { pubkey: PublicKey }) => ({
-        pubkey,
-        isSigner: false,
-        isWritable: true
-      })),
-      ...chunkedBidsPda[i].map(({ pubkey }: { pubkey: PublicKey }) => ({
-        pubkey,
-        isSigner: false,
-        isWritable: true
-      })),
-      ...chunkedPlayersPubkeys[i].map(({ pubkey }: { pubkey: PublicKey }) => ({
-        pubkey,
-        isSigner: false,  
-        isWritable: true
-      }))
-    ];
-
-    const instruction = new TransactionInstruction({
-      keys: chunkKeys,
-      programId: PROGRAM_ID,
-      data: instructionData
-    });
-
-    instructions.push(instruction);
-  }
-
- 
-  const transaction = new Transaction();
-  const latestBlockhash = await CONNECTION.getLatestBlockhash('confirmed');
-  transaction.recentBlockhash = latestBlockhash.blockhash;
-  transaction.feePayer = bidder;
- 
-  instructions.forEach(ix => transaction.add(ix));
- 
-  const fee = await transaction.getEstimatedFee(CONNECTION) || 5000000;
-
-  const totalCost = fee + bidAmount;
-
-  return {
-    transaction,
-    latestBlockhash,
-    totalCost,
-    playerPda,
-    bidPda,
-    chunksCount: chunkedPlayersPda.length
-  };
-};
  
 export const executeBidTransaction = async (
   wallet: any,
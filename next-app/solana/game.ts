@@ -7,32 +7,57 @@ import {
 import BN from 'bn.js';
 import { convertLamportsToUsdc, convertUsdcToLamports } from '@/lib/helper';
 import axios from 'axios';
+import bs58 from 'bs58';
+import * as borsh from '@coral-xyz/borsh';
 import { PROGRAM_ID, CONNECTION} from '@/lib/constant';
-import { getBidPda, getGamePda, getPlayerPda } from './pda';
-import { GameState, PlayerState } from './state';
-
-
-export const createGame = async (publicKey: PublicKey, gameId: number, bidAmount: number) => {
-  const gameIdBuffer = Buffer.alloc(8);
-  gameIdBuffer.writeBigUInt64LE(BigInt(gameId));
-
-  const bidCountBuffer = Buffer.alloc(8);  
-  bidCountBuffer.writeBigUInt64LE(BigInt(1));  
+import { getConfigPda, getGamePda, getLedgerPda, getPlayerPda, getStatsPda, getVaultPda } from './pda';
+import {
+  GameMode,
+  GameRules,
+  GameState,
+  PlayerState,
+  PriceCurve,
+  gameRulesSchema,
+  priceCurveSchema
+} from './state';
+
+
+export interface GameOptions {
+  rules?: GameRules;
+  mode?: GameMode;
+  priceCurve?: PriceCurve;
+  endsAt?: number;
+}
 
+const createGameSchema = borsh.struct([
+  borsh.u8('instruction'),
+  borsh.u64('game_id'),
+  borsh.u64('initial_bid_amount'),
+  borsh.option(gameRulesSchema, 'rules'),
+  borsh.u8('mode'),
+  borsh.option(priceCurveSchema, 'price_curve'),
+  borsh.option(borsh.u64(), 'ends_at'),
+]);
+
+export const createGame = async (publicKey: PublicKey, gameId: number, bidAmount: number, options: GameOptions = {}) => {
   const initialBidLamports = convertUsdcToLamports(bidAmount)
-  const initialBidAmountBuffer = Buffer.alloc(8);
-
-  initialBidAmountBuffer.writeBigUInt64LE(BigInt(initialBidLamports))
 
-  const instructionData = Buffer.concat([
-    Buffer.from([0]),  
-    gameIdBuffer,
-    initialBidAmountBuffer,
-  ]);
+  const instructionData = Buffer.alloc(256);
+  const instructionLength = createGameSchema.encode({
+    instruction: 0,
+    game_id: new BN(gameId),
+    initial_bid_amount: new BN(initialBidLamports),
+    rules: options.rules ?? null,
+    mode: options.mode ?? GameMode.Open,
+    price_curve: options.priceCurve ?? null,
+    ends_at: options.endsAt === undefined ? null : new BN(options.endsAt),
+  }, instructionData);
 
   const gamePda = getGamePda(new BN(gameId));
   const playerPda = getPlayerPda(new BN(gameId), publicKey, new BN(1))
-  const bidPda  = getBidPda(new BN(gameId), new BN(1))
+  const ledgerPda = getLedgerPda(new BN(gameId))
+  const statsPda = getStatsPda(new BN(gameId), publicKey)
+  const vaultPda = getVaultPda(new BN(gameId))
 
   const createGameIx = new TransactionInstruction({
     keys: [
@@ -40,10 +65,13 @@ export const createGame = async (publicKey: PublicKey, gameId: number, bidAmount
       { pubkey: publicKey, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
       { pubkey: playerPda, isSigner: false, isWritable: true },
-      { pubkey: bidPda, isSigner: false, isWritable: true },
+      { pubkey: ledgerPda, isSigner: false, isWritable: true },
+      { pubkey: statsPda, isSigner: false, isWritable: true },
+      { pubkey: getConfigPda(), isSigner: false, isWritable: false },
+      { pubkey: vaultPda, isSigner: false, isWritable: true },
     ],
     programId: PROGRAM_ID,
-    data: instructionData,
+    data: instructionData.subarray(0, instructionLength),
   });
 
   const latestBlockhash = await CONNECTION.getLatestBlockhash('confirmed');
@@ -57,7 +85,7 @@ export const createGame = async (publicKey: PublicKey, gameId: number, bidAmount
     fees = 50000000
   }
   const totalCost = fees + initialBidLamports;
-  return { transaction, latestBlockhash, totalCost, gamePda, playerPda, bidPda };
+  return { transaction, latestBlockhash, totalCost, gamePda, playerPda, ledgerPda };
 }
 
 
@@ -87,7 +115,7 @@ export const getAllGames = async () => {
       {
         memcmp: {
           offset: 0,
-          bytes: Buffer.from([0]).toString('base64'),  
+          bytes: bs58.encode(Uint8Array.from([GameState.KIND, GameState.VERSION])),
         },
       },
     ],
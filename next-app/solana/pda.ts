@@ -26,12 +26,37 @@ export function getPlayerPda(gameId: BN, publicKey: PublicKey, bidCount: BN): Pu
   return playerPda;
 }
 
-export function getBidPda(gameId: BN, bidId: BN): PublicKey {
+export function getLedgerPda(gameId: BN): PublicKey {
   const gameIdBuffer = Buffer.from(gameId.toArray('le', 8));
-  const bidIdBuffer = Buffer.from(bidId.toArray('le', 8));
-  const [bidPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from('bid'), gameIdBuffer, bidIdBuffer],
+  const [ledgerPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('ledger'), gameIdBuffer],
     PROGRAM_ID
   );
-  return bidPda;
-}
\ No newline at end of file
+  return ledgerPda;
+}
+
+export function getVaultPda(gameId: BN): PublicKey {
+  const gameIdBuffer = Buffer.from(gameId.toArray('le', 8));
+  const [vaultPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('vault'), gameIdBuffer],
+    PROGRAM_ID
+  );
+  return vaultPda;
+}
+
+export function getStatsPda(gameId: BN, publicKey: PublicKey): PublicKey {
+  const gameIdBuffer = Buffer.from(gameId.toArray('le', 8));
+  const [statsPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('stats'), gameIdBuffer, publicKey.toBuffer()],
+    PROGRAM_ID
+  );
+  return statsPda;
+}
+
+export function getConfigPda(): PublicKey {
+  const [configPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('config')],
+    PROGRAM_ID
+  );
+  return configPda;
+}
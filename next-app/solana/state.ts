@@ -1,7 +1,78 @@
 import { PublicKey } from "@solana/web3.js";
 import * as borsh from '@coral-xyz/borsh';
+import BN from "bn.js";
+
+export enum GameMode {
+  Open,
+  Sealed,
+  Dutch,
+}
+
+export enum CurveKind {
+  Linear,
+  Stepwise,
+}
+
+export interface GameRules {
+  bid_timeout: BN;
+  bid_multiplier_bps: BN;
+  royalty_window: BN;
+  snipe_window: BN;
+  snipe_extension: BN;
+  max_snipe_extension: BN;
+  reveal_window: BN;
+  max_bids_per_wallet: BN;
+  bid_cooldown: BN;
+  no_self_outbid: boolean;
+}
+
+export const gameRulesSchema = borsh.struct([
+  borsh.u64('bid_timeout'),
+  borsh.u64('bid_multiplier_bps'),
+  borsh.u64('royalty_window'),
+  borsh.u64('snipe_window'),
+  borsh.u64('snipe_extension'),
+  borsh.u64('max_snipe_extension'),
+  borsh.u64('reveal_window'),
+  borsh.u64('max_bids_per_wallet'),
+  borsh.u64('bid_cooldown'),
+  borsh.bool('no_self_outbid'),
+]);
+
+export interface PriceCurve {
+  kind: CurveKind;
+  start_price: BN;
+  floor_price: BN;
+  step_interval: BN;
+}
+
+export const priceCurveSchema = borsh.struct([
+  borsh.u8('kind'),
+  borsh.u64('start_price'),
+  borsh.u64('floor_price'),
+  borsh.u64('step_interval'),
+]);
+
+export enum GameStatus {
+  Active,
+  Settling,
+  Ended,
+}
+
+export enum AccountKind {
+  Game = 1,
+  Player = 2,
+}
+
+export const HEADER_LEN = 2;
+
+const hasHeader = (buffer: Buffer, kind: AccountKind, version: number) =>
+  buffer.length >= HEADER_LEN && buffer[0] === kind && buffer[1] === version;
 
 export class GameState {
+  static readonly KIND = AccountKind.Game;
+  static readonly VERSION = 2;
+
   game_id: number;
   initial_bid_amount: number;
   highest_bid: number;
@@ -10,7 +81,18 @@ export class GameState {
   last_bidder: PublicKey;
   prize_pool: number;
   platform_fee_percentage: number;
-  game_ended: boolean;
+  rules: GameRules | null;
+  deadline: number;
+  snipe_extension_used: number;
+  mint: PublicKey;
+  mode: GameMode;
+  reveal_deadline: number;
+  escrowed_deposits: number;
+  price_curve: PriceCurve | null;
+  started_at: number;
+  auto_bid_escrow: number;
+  ends_at: number;
+  status: GameStatus;
 
   constructor(fields: Partial<GameState> = {}) {
     this.game_id = fields.game_id || 0;
@@ -21,7 +103,18 @@ export class GameState {
     this.last_bidder = fields.last_bidder || new PublicKey(0);  
     this.prize_pool = fields.prize_pool || 0;
     this.platform_fee_percentage = fields.platform_fee_percentage || 0;
-    this.game_ended = fields.game_ended || false;
+    this.rules = fields.rules || null;
+    this.deadline = fields.deadline || 0;
+    this.snipe_extension_used = fields.snipe_extension_used || 0;
+    this.mint = fields.mint || new PublicKey(0);
+    this.mode = fields.mode || GameMode.Open;
+    this.reveal_deadline = fields.reveal_deadline || 0;
+    this.escrowed_deposits = fields.escrowed_deposits || 0;
+    this.price_curve = fields.price_curve || null;
+    this.started_at = fields.started_at || 0;
+    this.auto_bid_escrow = fields.auto_bid_escrow || 0;
+    this.ends_at = fields.ends_at || 0;
+    this.status = fields.status || GameStatus.Active;
   }
 
   static borshAccountSchema = borsh.struct([
@@ -33,30 +126,27 @@ export class GameState {
     borsh.publicKey('last_bidder'),
     borsh.u64('prize_pool'),
     borsh.u64('platform_fee_percentage'),
-    borsh.bool('game_ended'),  
+    gameRulesSchema.replicate('rules'),
+    borsh.u64('deadline'),
+    borsh.u64('snipe_extension_used'),
+    borsh.publicKey('mint'),
+    borsh.u8('mode'),
+    borsh.u64('reveal_deadline'),
+    borsh.u64('escrowed_deposits'),
+    priceCurveSchema.replicate('price_curve'),
+    borsh.u64('started_at'),
+    borsh.u64('auto_bid_escrow'),
+    borsh.u64('ends_at'),
+    borsh.u8('status'),
   ]);
 
   static deserialize(buffer?: Buffer): GameState | null {
-    if (!buffer) {
+    if (!buffer || !hasHeader(buffer, GameState.KIND, GameState.VERSION)) {
       return null;
     }
 
     try {
-      const { game_id, initial_bid_amount, highest_bid, last_bid_time, 
-              total_bids, last_bidder, prize_pool, platform_fee_percentage, game_ended 
-            } = this.borshAccountSchema.decode(buffer);
-
-        return new GameState({
-          game_id,
-          initial_bid_amount,
-          highest_bid,
-          last_bid_time,
-          total_bids,
-          last_bidder,
-          prize_pool,
-          platform_fee_percentage,
-          game_ended,
-        });
+      return new GameState(this.borshAccountSchema.decode(buffer.subarray(HEADER_LEN)));
     } catch (error) {
         console.error("Game deserialization error:", error);
         return null;
@@ -65,16 +155,25 @@ export class GameState {
 }
 
 export class PlayerState {
+  static readonly KIND = AccountKind.Player;
+  static readonly VERSION = 1;
+
   total_bid_amount: number;
   safe: boolean;
   royalty_earned: number;
   bid_count: number;
+  claimed: boolean;
+  bidder: PublicKey;
+  payer: PublicKey;
 
   constructor(fields: Partial<PlayerState> = {}) {
     this.total_bid_amount = fields.total_bid_amount || 0;
     this.safe = fields.safe || false;
     this.royalty_earned = fields.royalty_earned || 0;
     this.bid_count = fields.bid_count || 0;
+    this.claimed = fields.claimed || false;
+    this.bidder = fields.bidder || new PublicKey(0);
+    this.payer = fields.payer || new PublicKey(0);
   }
 
   static borshAccountSchema = borsh.struct([
@@ -82,24 +181,20 @@ export class PlayerState {
     borsh.bool('safe'),
     borsh.u64('royalty_earned'),
     borsh.u64('bid_count'),
+    borsh.bool('claimed'),
+    borsh.publicKey('bidder'),
+    borsh.publicKey('payer'),
   ]);
 
   static deserialize(buffer?: Buffer): PlayerState | null {
-    if (!buffer) {
+    if (!buffer || !hasHeader(buffer, PlayerState.KIND, PlayerState.VERSION)) {
       return null;
     }
     try {
-      const { total_bid_amount, safe, royalty_earned, bid_count } =
-        this.borshAccountSchema.decode(buffer);
-      return new PlayerState({
-        total_bid_amount,
-        safe,
-        royalty_earned,
-        bid_count,
-      });
+      return new PlayerState(this.borshAccountSchema.decode(buffer.subarray(HEADER_LEN)));
     } catch (error) {
       console.error("Player deserialization error:", error);
       return null;
     }
   }
-}
\ No newline at end of file
+}
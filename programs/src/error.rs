@@ -3,7 +3,7 @@ use thiserror::Error;
 
 #[derive(Error, Debug, Copy, Clone)]
 pub enum BiddingError {
-  #[error("Initial bid amount must be at least 14 SOL")]
+  #[error("Initial bid amount is below the platform minimum")]
   InsufficientInitialBid,
   #[error("Invalid game account")]
   InvalidGameAccount,
@@ -13,7 +13,7 @@ pub enum BiddingError {
   InvalidBidAccount,
   #[error("Game has already ended")]
   GameEnded,
-  #[error("Bid amount must be at least double the highest bid")]
+  #[error("Bid amount is below the minimum raise over the highest bid")]
   InsufficientBidAmount,
   #[error("Invalid new player account")]
   InvalidNewPlayerAccount,
@@ -47,6 +47,116 @@ pub enum BiddingError {
   PlayerAccountNotFound,
   #[error("Invalid instruction")]
   InvalidInstruction,
+  #[error("Bidding window has closed, the game must be ended")]
+  BiddingClosed,
+  #[error("Game is still accepting bids")]
+  GameStillActive,
+  #[error("Game settlement has already started")]
+  SettlementInProgress,
+  #[error("Invalid settlement account")]
+  InvalidSettlementAccount,
+  #[error("Settlement batch starts past the settlement cursor")]
+  SettlementOutOfOrder,
+  #[error("Player has nothing to claim")]
+  NothingToClaim,
+  #[error("Royalty has already been claimed")]
+  RoyaltyAlreadyClaimed,
+  #[error("Game has no challenging bids and must be cancelled")]
+  UncontestedGame,
+  #[error("Only uncontested games can be cancelled")]
+  GameContested,
+  #[error("Account is not the game creator")]
+  InvalidCreatorAccount,
+  #[error("Game has not been settled yet")]
+  GameNotEnded,
+  #[error("Player royalty must be claimed before closing")]
+  UnclaimedRoyalty,
+  #[error("Account is not the original payer")]
+  InvalidPayerAccount,
+  #[error("Invalid platform config account")]
+  InvalidConfigAccount,
+  #[error("Platform config parameters are out of range")]
+  InvalidConfig,
+  #[error("Signer is not the config authority")]
+  InvalidAuthority,
+  #[error("Game rules are outside the platform bounds")]
+  InvalidGameRules,
+  #[error("Invalid vault account")]
+  InvalidVaultAccount,
+  #[error("Invalid platform treasury account")]
+  InvalidTreasuryAccount,
+  #[error("Invalid token program")]
+  InvalidTokenProgram,
+  #[error("Token account has the wrong mint or owner")]
+  InvalidTokenAccount,
+  #[error("Invalid mint account")]
+  InvalidMint,
+  #[error("Destination account does not belong to the recipient")]
+  InvalidRecipientAccount,
+  #[error("Instruction is not supported in this game mode")]
+  InvalidGameMode,
+  #[error("Invalid commitment account")]
+  InvalidCommitmentAccount,
+  #[error("Reveal window is not open")]
+  RevealWindowClosed,
+  #[error("Revealed bid does not match the commitment")]
+  CommitmentMismatch,
+  #[error("Escrowed deposit does not cover the bid")]
+  InsufficientDeposit,
+  #[error("Invalid Dutch auction price curve")]
+  InvalidPriceCurve,
+  #[error("Invalid auto-bid account")]
+  InvalidAutoBidAccount,
+  #[error("Auto-bid budget cannot cover the next bid")]
+  AutoBidBudgetExhausted,
+  #[error("Auto-bidder already holds the highest bid")]
+  AutoBidNotNeeded,
+  #[error("Game still holds auto-bid budgets")]
+  AutoBidsOutstanding,
+  #[error("Invalid player balance account")]
+  InvalidBalanceAccount,
+  #[error("Player balance cannot cover the bid")]
+  InsufficientBalance,
+  #[error("Invalid session account")]
+  InvalidSessionAccount,
+  #[error("Session key has expired")]
+  SessionExpired,
+  #[error("Bid exceeds the session spending cap")]
+  SessionCapExceeded,
+  #[error("Session expiry is outside the allowed duration")]
+  InvalidSessionDuration,
+  #[error("Game end time leaves no room for bidding")]
+  InvalidEndTime,
+  #[error("Invalid player stats account")]
+  InvalidStatsAccount,
+  #[error("Wallet has reached the bid limit for this game")]
+  BidLimitReached,
+  #[error("Wallet must wait before bidding again")]
+  BidCooldownActive,
+  #[error("Wallet already holds the highest bid")]
+  SelfOutbid,
+  #[error("Account layout version is not supported")]
+  UnsupportedAccountVersion,
+  #[error("Account data does not match the expected account type")]
+  InvalidAccountType,
+  #[error("Account is not owned by the program")]
+  InvalidAccountOwner,
+  #[error("Account already uses the current layout")]
+  AccountAlreadyMigrated,
+  #[error("Ledger account does not match the game ledger")]
+  InvalidLedgerAccount,
+  #[error("Ledger account not found for ledger PDA")]
+  LedgerAccountNotFound,
+  #[error("Bid is not recorded in the game ledger")]
+  BidNotRecorded,
+  #[error("Invalid program data account")]
+  InvalidProgramDataAccount,
+  #[error("Bid has already been revealed")]
+  BidAlreadyRevealed,
+  #[error("Wallet bids must be signed by the wallet, session keys can only bid from a balance")]
+  WalletSignatureRequired,
+  #[error("Player accounts must be closed before the ledger")]
+  PlayerAccountsOutstanding,
 }
 
 impl From<BiddingError> for ProgramError {
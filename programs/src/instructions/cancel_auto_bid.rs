@@ -0,0 +1,75 @@
+use solana_program::{
+  account_info::{next_account_info, AccountInfo},
+  entrypoint::ProgramResult,
+  pubkey::Pubkey,
+  program_error::ProgramError,
+  sysvar::{clock::Clock, Sysvar},
+};
+use crate::state::{AutoBid, GameState, GameStatus};
+use crate::error::BiddingError;
+use crate::layout::{load_account, store_account};
+use crate::utils::{
+  game_pda_seeds,
+  auto_bid_pda_seeds,
+  check_vault,
+  bidding_closes_at,
+  next_token_accounts,
+  pay_from_vault,
+  close_pda
+};
+
+pub fn cancel_auto_bid(
+  program_id: &Pubkey,
+  accounts: &[AccountInfo],
+  game_id: u64,
+) -> ProgramResult {
+  let accounts_iter = &mut accounts.iter();
+  let game_account = next_account_info(accounts_iter)?;
+  let player_account = next_account_info(accounts_iter)?;
+  let auto_bid_account = next_account_info(accounts_iter)?;
+  let vault_account = next_account_info(accounts_iter)?;
+
+  let (game_pda, _) = game_pda_seeds(game_id, program_id);
+  if *game_account.key != game_pda || game_account.owner != program_id {
+    return Err(BiddingError::InvalidGameAccount.into());
+  }
+
+  let mut game_state = load_account::<GameState>(program_id, game_account)?;
+  let current_time = Clock::get()?.unix_timestamp as u64;
+  let bidding_open = game_state.status == GameStatus::Active && current_time <= bidding_closes_at(&game_state);
+
+  if bidding_open && !player_account.is_signer {
+    return Err(ProgramError::MissingRequiredSignature);
+  }
+
+  check_vault(program_id, game_id, &game_state.mint, vault_account)?;
+  let token_accounts = next_token_accounts(&game_state.mint, accounts_iter)?;
+  let refund_destination = token_accounts.map_or(player_account, |token_accounts| token_accounts.token_account);
+
+  let (auto_bid_pda, _) = auto_bid_pda_seeds(game_id, player_account.key, program_id);
+  if *auto_bid_account.key != auto_bid_pda || auto_bid_account.owner != program_id {
+    return Err(BiddingError::InvalidAutoBidAccount.into());
+  }
+
+  let auto_bid = load_account::<AutoBid>(program_id, auto_bid_account)
+    .map_err(|_| BiddingError::InvalidAutoBidAccount)?;
+
+  game_state.auto_bid_escrow -= auto_bid.budget;
+  store_account(game_account, &game_state)?;
+  close_pda(auto_bid_account, player_account)?;
+
+  if auto_bid.budget > 0 {
+    pay_from_vault(
+      program_id,
+      game_id,
+      game_account,
+      vault_account,
+      refund_destination,
+      player_account.key,
+      token_accounts,
+      auto_bid.budget,
+    )?;
+  }
+
+  Ok(())
+}
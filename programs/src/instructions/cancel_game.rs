@@ -0,0 +1,91 @@
+use solana_program::{
+  account_info::{next_account_info, AccountInfo},
+  entrypoint::ProgramResult,
+  pubkey::Pubkey,
+  sysvar::{clock::Clock, Sysvar},
+};
+use crate::state::{GameState, GameStatus};
+use crate::error::BiddingError;
+use crate::layout::load_account;
+use crate::utils::{
+  game_pda_seeds,
+  player_pda_seeds,
+  ledger_pda_seeds,
+  stats_pda_seeds,
+  check_vault,
+  bidding_closes_at,
+  next_token_accounts,
+  close_vault,
+  close_pda
+};
+
+pub fn cancel_game(
+  program_id: &Pubkey,
+  accounts: &[AccountInfo],
+  game_id: u64,
+) -> ProgramResult {
+  let accounts_iter = &mut accounts.iter();
+  let game_account = next_account_info(accounts_iter)?;
+  let creator_account = next_account_info(accounts_iter)?;
+  let player_account = next_account_info(accounts_iter)?;
+  let ledger_account = next_account_info(accounts_iter)?;
+  let stats_account = next_account_info(accounts_iter)?;
+  let vault_account = next_account_info(accounts_iter)?;
+
+  let (game_pda, _) = game_pda_seeds(game_id, program_id);
+  if *game_account.key != game_pda || game_account.owner != program_id {
+    return Err(BiddingError::InvalidGameAccount.into());
+  }
+
+  let game_state = load_account::<GameState>(program_id, game_account)?;
+
+  if game_state.status != GameStatus::Active {
+    return Err(BiddingError::GameEnded.into());
+  }
+
+  if game_state.total_bids != 1 || game_state.revealed_leader().is_some() {
+    return Err(BiddingError::GameContested.into());
+  }
+
+  let current_time = Clock::get()?.unix_timestamp as u64;
+
+  if current_time <= bidding_closes_at(&game_state) {
+    return Err(BiddingError::GameStillActive.into());
+  }
+
+  if game_state.auto_bid_escrow > 0 {
+    return Err(BiddingError::AutoBidsOutstanding.into());
+  }
+
+  if *creator_account.key != game_state.last_bidder {
+    return Err(BiddingError::InvalidCreatorAccount.into());
+  }
+
+  let (player_pda, _) = player_pda_seeds(game_id, creator_account.key, 1, program_id);
+  if *player_account.key != player_pda || player_account.owner != program_id {
+    return Err(BiddingError::InvalidPlayerAccount.into());
+  }
+
+  let (ledger_pda, _) = ledger_pda_seeds(game_id, program_id);
+  if *ledger_account.key != ledger_pda || ledger_account.owner != program_id {
+    return Err(BiddingError::InvalidLedgerAccount.into());
+  }
+
+  let (stats_pda, _) = stats_pda_seeds(game_id, creator_account.key, program_id);
+  if *stats_account.key != stats_pda {
+    return Err(BiddingError::InvalidStatsAccount.into());
+  }
+
+  check_vault(program_id, game_id, &game_state.mint, vault_account)?;
+  let token_accounts = next_token_accounts(&game_state.mint, accounts_iter)?;
+
+  close_vault(program_id, game_id, game_account, vault_account, creator_account, token_accounts)?;
+  close_pda(ledger_account, creator_account)?;
+  close_pda(player_account, creator_account)?;
+  if stats_account.owner == program_id {
+    close_pda(stats_account, creator_account)?;
+  }
+  close_pda(game_account, creator_account)?;
+
+  Ok(())
+}
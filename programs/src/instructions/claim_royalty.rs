@@ -0,0 +1,90 @@
+use solana_program::{
+  account_info::{next_account_info, AccountInfo},
+  entrypoint::ProgramResult,
+  pubkey::Pubkey,
+  program_error::ProgramError,
+};
+use crate::state::{GameState, PlayerState};
+use crate::error::BiddingError;
+use crate::layout::{load_account, store_account};
+use crate::utils::{
+  game_pda_seeds,
+  player_pda_seeds,
+  check_vault,
+  next_token_accounts,
+  pay_from_vault,
+  check_balance_account
+};
+
+pub fn claim_royalty(
+  program_id: &Pubkey,
+  accounts: &[AccountInfo],
+  game_id: u64,
+  bid_count: u64,
+) -> ProgramResult {
+  let accounts_iter = &mut accounts.iter();
+  let game_account = next_account_info(accounts_iter)?;
+  let player_account = next_account_info(accounts_iter)?;
+  let bidder_account = next_account_info(accounts_iter)?;
+  let vault_account = next_account_info(accounts_iter)?;
+
+  if !bidder_account.is_signer {
+    return Err(ProgramError::MissingRequiredSignature);
+  }
+
+  let (game_pda, _) = game_pda_seeds(game_id, program_id);
+  if *game_account.key != game_pda || game_account.owner != program_id {
+    return Err(BiddingError::InvalidGameAccount.into());
+  }
+
+  let game_state = load_account::<GameState>(program_id, game_account)?;
+
+  check_vault(program_id, game_id, &game_state.mint, vault_account)?;
+  let token_accounts = next_token_accounts(&game_state.mint, accounts_iter)?;
+  let balance_account = match token_accounts {
+    None => accounts_iter.next(),
+    Some(_) => None,
+  };
+
+  let (destination, recipient) = match (token_accounts, balance_account) {
+    (Some(token_accounts), _) => (token_accounts.token_account, bidder_account.key),
+    (None, Some(balance_account)) => {
+      check_balance_account(program_id, balance_account, bidder_account.key)?;
+      (balance_account, balance_account.key)
+    },
+    (None, None) => (bidder_account, bidder_account.key),
+  };
+
+  let (player_pda, _) = player_pda_seeds(game_id, bidder_account.key, bid_count, program_id);
+  if *player_account.key != player_pda || player_account.owner != program_id {
+    return Err(BiddingError::InvalidPlayerAccount.into());
+  }
+
+  let mut player_state = load_account::<PlayerState>(program_id, player_account)?;
+
+  if !player_state.safe {
+    return Err(BiddingError::NothingToClaim.into());
+  }
+
+  if player_state.claimed {
+    return Err(BiddingError::RoyaltyAlreadyClaimed.into());
+  }
+
+  let amount = player_state.total_bid_amount + player_state.royalty_earned;
+
+  player_state.claimed = true;
+  store_account(player_account, &player_state)?;
+
+  pay_from_vault(
+    program_id,
+    game_id,
+    game_account,
+    vault_account,
+    destination,
+    recipient,
+    token_accounts,
+    amount,
+  )?;
+
+  Ok(())
+}
@@ -0,0 +1,154 @@
+use solana_program::{
+  account_info::{next_account_info, AccountInfo},
+  entrypoint::ProgramResult,
+  pubkey::Pubkey,
+  program_error::ProgramError,
+  sysvar::{clock::Clock, Sysvar},
+};
+use crate::state::{AccountKind, GameState, GameStatus, PlayerState, PlayerStats};
+use crate::error::BiddingError;
+use crate::layout::load_account;
+use crate::ledger::release_bid;
+use crate::instructions::reveal_bid::load_commitment;
+use crate::utils::{
+  game_pda_seeds,
+  player_pda_seeds,
+  stats_pda_seeds,
+  close_pda
+};
+
+pub fn close_accounts(
+  program_id: &Pubkey,
+  accounts: &[AccountInfo],
+  game_id: u64,
+) -> ProgramResult {
+  let accounts_iter = &mut accounts.iter();
+  let game_account = next_account_info(accounts_iter)?;
+  let ledger_account = next_account_info(accounts_iter)?;
+
+  let (game_pda, _) = game_pda_seeds(game_id, program_id);
+  if *game_account.key != game_pda {
+    return Err(BiddingError::InvalidGameAccount.into());
+  }
+
+  let game_state = if game_account.data_is_empty() {
+    None
+  } else if game_account.owner == program_id {
+    Some(load_account::<GameState>(program_id, game_account)?)
+  } else {
+    return Err(BiddingError::InvalidGameAccount.into());
+  };
+
+  let remaining_accounts = &accounts[2..];
+  if remaining_accounts.len() % 2 != 0 {
+    return Err(ProgramError::NotEnoughAccountKeys);
+  }
+
+  let current_time = Clock::get()?.unix_timestamp as u64;
+
+  for chunk in remaining_accounts.chunks(2) {
+    let account = &chunk[0];
+    let payer_account = &chunk[1];
+
+    if account.owner != program_id {
+      return Err(BiddingError::InvalidAccountOwner.into());
+    }
+
+    let kind = account.try_borrow_data()?.first().copied();
+
+    match kind {
+      Some(kind) if kind == AccountKind::Player as u8 => {
+        close_player(program_id, game_id, game_state.as_ref(), ledger_account, account, payer_account, accounts)?
+      },
+      Some(kind) if kind == AccountKind::Stats as u8 => {
+        close_stats(program_id, game_id, game_state.as_ref(), account, payer_account)?
+      },
+      Some(kind) if kind == AccountKind::Commitment as u8 => {
+        close_commitment(program_id, game_id, game_state.as_ref(), account, payer_account, current_time)?
+      },
+      _ => return Err(BiddingError::InvalidAccountType.into()),
+    }
+  }
+
+  Ok(())
+}
+
+fn close_player<'a>(
+  program_id: &Pubkey,
+  game_id: u64,
+  game_state: Option<&GameState>,
+  ledger_account: &AccountInfo<'a>,
+  player_account: &AccountInfo<'a>,
+  payer_account: &AccountInfo<'a>,
+  accounts: &[AccountInfo<'a>],
+) -> ProgramResult {
+  if game_state.map(|game_state| game_state.status) != Some(GameStatus::Ended) {
+    return Err(BiddingError::GameNotEnded.into());
+  }
+
+  let player_state = load_account::<PlayerState>(program_id, player_account)?;
+
+  let (player_pda, _) = player_pda_seeds(game_id, &player_state.bidder, player_state.bid_count, program_id);
+  if *player_account.key != player_pda {
+    return Err(BiddingError::InvalidPlayerAccount.into());
+  }
+
+  if player_state.safe && !player_state.claimed {
+    return Err(BiddingError::UnclaimedRoyalty.into());
+  }
+
+  if *payer_account.key != player_state.payer {
+    return Err(BiddingError::InvalidPayerAccount.into());
+  }
+
+  close_pda(player_account, payer_account)?;
+  release_bid(program_id, ledger_account, game_id, payer_account, accounts)
+}
+
+fn close_stats<'a>(
+  program_id: &Pubkey,
+  game_id: u64,
+  game_state: Option<&GameState>,
+  stats_account: &AccountInfo<'a>,
+  payer_account: &AccountInfo<'a>,
+) -> ProgramResult {
+  if game_state.map(|game_state| game_state.status) != Some(GameStatus::Ended) {
+    return Err(BiddingError::GameNotEnded.into());
+  }
+
+  let stats = load_account::<PlayerStats>(program_id, stats_account)?;
+
+  let (stats_pda, _) = stats_pda_seeds(game_id, &stats.player, program_id);
+  if *stats_account.key != stats_pda {
+    return Err(BiddingError::InvalidStatsAccount.into());
+  }
+
+  if *payer_account.key != stats.payer {
+    return Err(BiddingError::InvalidPayerAccount.into());
+  }
+
+  close_pda(stats_account, payer_account)
+}
+
+fn close_commitment<'a>(
+  program_id: &Pubkey,
+  game_id: u64,
+  game_state: Option<&GameState>,
+  commitment_account: &AccountInfo<'a>,
+  bidder_account: &AccountInfo<'a>,
+  current_time: u64,
+) -> ProgramResult {
+  if let Some(game_state) = game_state {
+    if current_time <= game_state.reveal_deadline {
+      return Err(BiddingError::GameStillActive.into());
+    }
+
+    if game_state.revealed_leader() == Some(*bidder_account.key) {
+      return Err(BiddingError::GameNotEnded.into());
+    }
+  }
+
+  load_commitment(program_id, game_id, commitment_account, bidder_account.key)?;
+
+  close_pda(commitment_account, bidder_account)
+}
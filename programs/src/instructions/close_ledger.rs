@@ -0,0 +1,25 @@
+use solana_program::{
+  account_info::{next_account_info, AccountInfo},
+  entrypoint::ProgramResult,
+  pubkey::Pubkey,
+};
+use crate::error::BiddingError;
+use crate::ledger::close_ledger_account;
+use crate::utils::ledger_pda_seeds;
+
+pub fn close_ledger(
+  program_id: &Pubkey,
+  accounts: &[AccountInfo],
+  game_id: u64,
+) -> ProgramResult {
+  let accounts_iter = &mut accounts.iter();
+  let ledger_account = next_account_info(accounts_iter)?;
+  let payer_account = next_account_info(accounts_iter)?;
+
+  let (ledger_pda, _) = ledger_pda_seeds(game_id, program_id);
+  if *ledger_account.key != ledger_pda {
+    return Err(BiddingError::InvalidLedgerAccount.into());
+  }
+
+  close_ledger_account(program_id, ledger_account, game_id, payer_account)
+}
@@ -0,0 +1,106 @@
+use solana_program::{
+  account_info::{next_account_info, AccountInfo},
+  entrypoint::ProgramResult,
+  pubkey::Pubkey,
+  program_error::ProgramError,
+  sysvar::{clock::Clock, Sysvar},
+};
+use crate::state::{Commitment, GameMode, GameState, GameStatus, COMMITMENT_ACCOUNT_SIZE};
+use crate::error::BiddingError;
+use crate::layout::{load_account, store_account};
+use crate::utils::{
+  game_pda_seeds,
+  commitment_pda_seeds,
+  check_vault,
+  next_token_accounts,
+  deposit_to_vault,
+  create_pda_account
+};
+
+pub fn commit_bid(
+  program_id: &Pubkey,
+  accounts: &[AccountInfo],
+  game_id: u64,
+  commitment: [u8; 32],
+  deposit: u64,
+) -> ProgramResult {
+  let accounts_iter = &mut accounts.iter();
+  let system_program = next_account_info(accounts_iter)?;
+  let game_account = next_account_info(accounts_iter)?;
+  let bidder_account = next_account_info(accounts_iter)?;
+  let commitment_account = next_account_info(accounts_iter)?;
+  let vault_account = next_account_info(accounts_iter)?;
+
+  if !bidder_account.is_signer {
+    return Err(ProgramError::MissingRequiredSignature);
+  }
+
+  let (game_pda, _) = game_pda_seeds(game_id, program_id);
+  if *game_account.key != game_pda || game_account.owner != program_id {
+    return Err(BiddingError::InvalidGameAccount.into());
+  }
+
+  let mut game_state = load_account::<GameState>(program_id, game_account)?;
+
+  if game_state.status != GameStatus::Active {
+    return Err(BiddingError::GameEnded.into());
+  }
+
+  if game_state.mode != GameMode::Sealed {
+    return Err(BiddingError::InvalidGameMode.into());
+  }
+
+  let current_time = Clock::get()?.unix_timestamp as u64;
+
+  if current_time > game_state.deadline {
+    return Err(BiddingError::BiddingClosed.into());
+  }
+
+  check_vault(program_id, game_id, &game_state.mint, vault_account)?;
+  let token_accounts = next_token_accounts(&game_state.mint, accounts_iter)?;
+
+  let (commitment_pda, commitment_bump) = commitment_pda_seeds(game_id, bidder_account.key, program_id);
+  if *commitment_account.key != commitment_pda {
+    return Err(BiddingError::InvalidCommitmentAccount.into());
+  }
+
+  create_pda_account(
+    program_id,
+    bidder_account,
+    commitment_account,
+    system_program,
+    COMMITMENT_ACCOUNT_SIZE,
+    &[
+      b"commitment",
+      &game_id.to_le_bytes(),
+      bidder_account.key.as_ref(),
+      &[commitment_bump]
+    ],
+  )?;
+
+  let received_amount = deposit_to_vault(
+    bidder_account,
+    vault_account,
+    system_program,
+    token_accounts,
+    deposit,
+  )?;
+
+  if received_amount == 0 {
+    return Err(BiddingError::InsufficientDeposit.into());
+  }
+
+  game_state.escrowed_deposits += received_amount;
+  store_account(game_account, &game_state)?;
+
+  let commitment_state = Commitment {
+    game_id,
+    bidder: *bidder_account.key,
+    hash: commitment,
+    deposit: received_amount,
+  };
+
+  store_account(commitment_account, &commitment_state)?;
+
+  Ok(())
+}
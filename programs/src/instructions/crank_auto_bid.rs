@@ -0,0 +1,103 @@
+use solana_program::{
+  account_info::{next_account_info, AccountInfo},
+  entrypoint::ProgramResult,
+  pubkey::Pubkey,
+  program_error::ProgramError,
+  sysvar::{clock::Clock, Sysvar},
+};
+use crate::state::{AutoBid, GameMode, GameStatus};
+use crate::error::BiddingError;
+use crate::layout::{load_account, store_account};
+use crate::zero_copy::{load_view_mut, GameStateView};
+use crate::instructions::place_bid::accept_bid;
+use crate::utils::{
+  game_pda_seeds,
+  auto_bid_pda_seeds,
+  min_next_bid
+};
+
+pub fn crank_auto_bid(
+  program_id: &Pubkey,
+  accounts: &[AccountInfo],
+  game_id: u64,
+  bid_count: u64,
+) -> ProgramResult {
+  let accounts_iter = &mut accounts.iter();
+  let system_program = next_account_info(accounts_iter)?;
+  let game_account = next_account_info(accounts_iter)?;
+  let cranker_account = next_account_info(accounts_iter)?;
+  let auto_bid_account = next_account_info(accounts_iter)?;
+  let ledger_account = next_account_info(accounts_iter)?;
+  let new_player_account = next_account_info(accounts_iter)?;
+  let stats_account = next_account_info(accounts_iter)?;
+
+  if !cranker_account.is_signer {
+    return Err(ProgramError::MissingRequiredSignature);
+  }
+
+  let (game_pda, _) = game_pda_seeds(game_id, program_id);
+  if *game_account.key != game_pda || game_account.owner != program_id {
+    return Err(BiddingError::InvalidGameAccount.into());
+  }
+
+  let mut game = load_view_mut::<GameStateView>(program_id, game_account)?;
+
+  if game.status()? != GameStatus::Active {
+    return Err(BiddingError::GameEnded.into());
+  }
+
+  if game.mode()? != GameMode::Open {
+    return Err(BiddingError::InvalidGameMode.into());
+  }
+
+  let current_time = Clock::get()?.unix_timestamp as u64;
+
+  if current_time > game.deadline.get() {
+    return Err(BiddingError::BiddingClosed.into());
+  }
+
+  if auto_bid_account.owner != program_id {
+    return Err(BiddingError::InvalidAutoBidAccount.into());
+  }
+
+  let mut auto_bid = load_account::<AutoBid>(program_id, auto_bid_account)
+    .map_err(|_| BiddingError::InvalidAutoBidAccount)?;
+
+  let (auto_bid_pda, _) = auto_bid_pda_seeds(game_id, &auto_bid.player, program_id);
+  if *auto_bid_account.key != auto_bid_pda {
+    return Err(BiddingError::InvalidAutoBidAccount.into());
+  }
+
+  if auto_bid.player == game.last_bidder {
+    return Err(BiddingError::AutoBidNotNeeded.into());
+  }
+
+  if bid_count != game.total_bids.get() + 1 {
+    return Err(BiddingError::BidCountMismatch.into());
+  }
+
+  let amount = min_next_bid(game.highest_bid.get(), game.rules.bid_multiplier_bps.get());
+
+  if amount > auto_bid.budget {
+    return Err(BiddingError::AutoBidBudgetExhausted.into());
+  }
+
+  auto_bid.budget -= amount;
+  let auto_bid_escrow = game.auto_bid_escrow.get() - amount;
+  game.auto_bid_escrow.set(auto_bid_escrow);
+
+  accept_bid(
+    program_id,
+    system_program,
+    cranker_account,
+    &auto_bid.player,
+    ledger_account,
+    new_player_account,
+    stats_account,
+    &mut game,
+    amount,
+    current_time,
+  )?;
+
+  store_account(auto_bid_account, &auto_bid)
+}
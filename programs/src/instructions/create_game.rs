@@ -2,39 +2,81 @@ use solana_program::{
   account_info::{next_account_info, AccountInfo},
   entrypoint::ProgramResult,
   pubkey::Pubkey,
-  program::invoke_signed,
   program::invoke,
-  system_instruction,
-  sysvar::{clock::Clock, rent::Rent, Sysvar},
+  sysvar::{clock::Clock, Sysvar},
 };
-use borsh::BorshSerialize;
 use crate::state::{
   GameState, 
+  GameRules,
+  GameMode,
+  GameStatus,
+  PriceCurve,
   PlayerState, 
-  Bid, 
+  PlayerStats,
   GAME_ACCOUNT_SIZE, 
-  PLAYER_ACCOUNT_SIZE, 
-  BID_ACCOUNT_SIZE
+  PLAYER_ACCOUNT_SIZE,
+  STATS_ACCOUNT_SIZE
 };
 use crate::error::BiddingError;
-use crate::utils::{game_pda_seeds, player_pda_seeds, bid_pda_seeds};
+use crate::layout::store_account;
+use crate::ledger::{create_ledger, append_bid};
+use crate::zero_copy::BidView;
+use crate::utils::{
+  game_pda_seeds,
+  player_pda_seeds,
+  stats_pda_seeds,
+  vault_pda_seeds,
+  load_config,
+  rules_within_bounds,
+  price_curve_valid,
+  read_token_accounts,
+  vault_account_len,
+  deposit_to_vault,
+  create_pda_account,
+  create_owned_account,
+  TokenAccounts
+};
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_game(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     game_id: u64,
     initial_bid_amount: u64,
+    rules: Option<GameRules>,
+    mode: GameMode,
+    price_curve: Option<PriceCurve>,
+    ends_at: Option<u64>,
 ) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
     let game_account = next_account_info(accounts_iter)?;
     let payer_account = next_account_info(accounts_iter)?;
     let system_program = next_account_info(accounts_iter)?;
     let player_account = next_account_info(accounts_iter)?;
-    let bid_account = next_account_info(accounts_iter)?;
+    let ledger_account = next_account_info(accounts_iter)?;
+    let stats_account = next_account_info(accounts_iter)?;
+    let config_account = next_account_info(accounts_iter)?;
+    let vault_account = next_account_info(accounts_iter)?;
+    let token_accounts = match accounts_iter.len() {
+      0 => None,
+      _ => Some(read_token_accounts(accounts_iter)?),
+    };
+    let mint = token_accounts.map_or(Pubkey::default(), |token_accounts| *token_accounts.mint.key);
 
-    if initial_bid_amount < 14_000_000 {
-      return Err(BiddingError::InsufficientInitialBid.into());
+    let config = load_config(program_id, config_account)?;
+
+    let rules = rules.unwrap_or(config.default_rules);
+    if !rules_within_bounds(&rules, &config.min_rules, &config.max_rules) {
+      return Err(BiddingError::InvalidGameRules.into());
+    }
+    if mode == GameMode::Sealed && rules.reveal_window == 0 {
+      return Err(BiddingError::InvalidGameRules.into());
     }
+    let price_curve = match (mode, price_curve) {
+      (GameMode::Dutch, Some(curve)) if price_curve_valid(&curve, rules.bid_timeout) => curve,
+      (GameMode::Open | GameMode::Sealed, None) => PriceCurve::default(),
+      _ => return Err(BiddingError::InvalidPriceCurve.into()),
+    };
     let (game_pda, game_bump) = game_pda_seeds(
       game_id, 
       program_id
@@ -51,95 +93,158 @@ pub fn create_game(
     if *player_account.key != player_pda {
         return Err(BiddingError::InvalidPlayerAccount.into());
     }
-    let (bid_pda, bid_bump) = bid_pda_seeds(
+    let (stats_pda, stats_bump) = stats_pda_seeds(
+      game_id, 
+      payer_account.key, 
+      program_id
+    );
+    if *stats_account.key != stats_pda {
+      return Err(BiddingError::InvalidStatsAccount.into());
+    }
+    let (vault_pda, vault_bump) = vault_pda_seeds(
       game_id, 
-      1, 
       program_id
     );
-    if *bid_account.key != bid_pda {
-      return Err(BiddingError::InvalidBidAccount.into());
+    if *vault_account.key != vault_pda {
+      return Err(BiddingError::InvalidVaultAccount.into());
     }
 
-    let rent = Rent::get()?;
-    let game_rent = rent.minimum_balance(std::mem::size_of::<GameState>());
-    let player_rent = rent.minimum_balance(std::mem::size_of::<PlayerState>());
-    let bid_rent = rent.minimum_balance(std::mem::size_of::<Bid>());
+    let vault_space = match token_accounts {
+      Some(token_accounts) => vault_account_len(token_accounts.mint)?,
+      None => 0,
+    };
+    let vault_owner = token_accounts.map_or(program_id, |token_accounts| token_accounts.token_program.key);
  
-    invoke_signed(
-      &system_instruction::create_account(
-        payer_account.key,
-        game_account.key,
-        game_rent,
-        GAME_ACCOUNT_SIZE as u64,
-        program_id,
-      ),
-      &[payer_account.clone(), game_account.clone(), system_program.clone()],
-      &[&[b"game", &game_id.to_le_bytes(), &[game_bump]]],
+    create_pda_account(
+      program_id,
+      payer_account,
+      game_account,
+      system_program,
+      GAME_ACCOUNT_SIZE,
+      &[b"game", &game_id.to_le_bytes(), &[game_bump]],
     )?;
   
-    invoke_signed(
-      &system_instruction::create_account(
-        payer_account.key,
-        player_account.key,
-        player_rent,
-        PLAYER_ACCOUNT_SIZE as u64,
-        program_id,
-      ),
-      &[payer_account.clone(), player_account.clone(), system_program.clone()],
-      &[&[b"player", &game_id.to_le_bytes(), payer_account.key.as_ref(), &1u64.to_le_bytes(), &[player_bump]]],
+    create_pda_account(
+      program_id,
+      payer_account,
+      player_account,
+      system_program,
+      PLAYER_ACCOUNT_SIZE,
+      &[b"player", &game_id.to_le_bytes(), payer_account.key.as_ref(), &1u64.to_le_bytes(), &[player_bump]],
     )?;
     
-    invoke_signed(
-        &system_instruction::create_account(
-            payer_account.key,
-            bid_account.key,
-            bid_rent,
-            BID_ACCOUNT_SIZE as u64,
-            program_id,
-        ),
-        &[payer_account.clone(), bid_account.clone(), system_program.clone()],
-        &[&[b"bid", &game_id.to_le_bytes(), &1u64.to_le_bytes(), &[bid_bump]]],
+    create_pda_account(
+      program_id,
+      payer_account,
+      stats_account,
+      system_program,
+      STATS_ACCOUNT_SIZE,
+      &[b"stats", &game_id.to_le_bytes(), payer_account.key.as_ref(), &[stats_bump]],
+    )?;
+
+    create_ledger(program_id, payer_account, ledger_account, system_program, game_id)?;
+
+    create_owned_account(
+      program_id,
+      payer_account,
+      vault_account,
+      system_program,
+      vault_space,
+      vault_owner,
+      &[b"vault", &game_id.to_le_bytes(), &[vault_bump]],
+    )?;
+
+    if let Some(TokenAccounts { token_program, mint, .. }) = token_accounts {
+      invoke(
+        &spl_token_2022::instruction::initialize_account3(
+          token_program.key,
+          vault_account.key,
+          mint.key,
+          game_account.key,
+        )?,
+        &[vault_account.clone(), mint.clone(), token_program.clone()],
+      )?;
+    }
+
+    let received_amount = deposit_to_vault(
+      payer_account,
+      vault_account,
+      system_program,
+      token_accounts,
+      initial_bid_amount,
     )?;
+
+    if received_amount < config.min_initial_bid {
+      return Err(BiddingError::InsufficientInitialBid.into());
+    }
   
     let current_time = Clock::get()?.unix_timestamp as u64;
+    let ends_at = ends_at.unwrap_or(u64::MAX);
+    let bidding_ends_at = match mode {
+      GameMode::Open | GameMode::Dutch => ends_at,
+      GameMode::Sealed => ends_at.saturating_sub(rules.reveal_window),
+    };
+    if bidding_ends_at <= current_time {
+      return Err(BiddingError::InvalidEndTime.into());
+    }
+    let deadline = (current_time + rules.bid_timeout).min(bidding_ends_at);
+    let reveal_deadline = match mode {
+      GameMode::Open | GameMode::Dutch => deadline,
+      GameMode::Sealed => deadline + rules.reveal_window,
+    };
 
     let game_state = GameState {
         game_id,
-        initial_bid_amount,
-        highest_bid: initial_bid_amount,
+        initial_bid_amount: received_amount,
+        highest_bid: received_amount,
         last_bid_time: current_time,
         total_bids: 1,
         last_bidder: *payer_account.key,
-        prize_pool: initial_bid_amount,
-        platform_fee_percentage: 10,
-        game_ended: false,
+        prize_pool: received_amount,
+        platform_fee_percentage: config.platform_fee_percentage,
+        rules,
+        deadline,
+        snipe_extension_used: 0,
+        mint,
+        mode,
+        reveal_deadline,
+        escrowed_deposits: 0,
+        price_curve,
+        started_at: current_time,
+        auto_bid_escrow: 0,
+        ends_at,
+        status: GameStatus::Active,
     };
  
     let player_state = PlayerState {
-        total_bid_amount: initial_bid_amount,
+        total_bid_amount: received_amount,
         safe: false,
         royalty_earned: 0,
         bid_count: 1,
+        claimed: false,
+        bidder: *payer_account.key,
+        payer: *payer_account.key,
     };
   
-    let bid = Bid {
+    let player_stats = PlayerStats {
+        game_id,
+        player: *payer_account.key,
+        bid_count: 1,
+        last_bid_time: current_time,
+        payer: *payer_account.key,
+    };
+  
+    let bid = BidView {
         bidder: *payer_account.key,
-        amount: initial_bid_amount,
-        timestamp: current_time,
+        amount: received_amount.into(),
+        timestamp: current_time.into(),
+        payer: *payer_account.key,
     };
   
-    game_state.serialize(&mut &mut game_account.data.borrow_mut()[..])?;
-    player_state.serialize(&mut &mut player_account.data.borrow_mut()[..])?;
-    bid.serialize(&mut &mut bid_account.data.borrow_mut()[..])?;
- 
-    invoke(
-      &system_instruction::transfer(
-          payer_account.key,
-          game_account.key,
-          initial_bid_amount,
-      ),
-      &[payer_account.clone(), game_account.clone(), system_program.clone()],
-    )?;
+    store_account(game_account, &game_state)?;
+    store_account(player_account, &player_state)?;
+    store_account(stats_account, &player_stats)?;
+    append_bid(program_id, payer_account, ledger_account, system_program, game_id, 1, bid)?;
  
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,53 @@
+use solana_program::{
+  account_info::{next_account_info, AccountInfo},
+  entrypoint::ProgramResult,
+  pubkey::Pubkey,
+  program::invoke,
+  program_error::ProgramError,
+  system_instruction,
+};
+use crate::state::{Balance, BALANCE_ACCOUNT_SIZE};
+use crate::error::BiddingError;
+use crate::layout::store_account;
+use crate::utils::{balance_pda_seeds, check_balance_account, create_pda_account};
+
+pub fn deposit(
+  program_id: &Pubkey,
+  accounts: &[AccountInfo],
+  amount: u64,
+) -> ProgramResult {
+  let accounts_iter = &mut accounts.iter();
+  let system_program = next_account_info(accounts_iter)?;
+  let owner_account = next_account_info(accounts_iter)?;
+  let balance_account = next_account_info(accounts_iter)?;
+
+  if !owner_account.is_signer {
+    return Err(ProgramError::MissingRequiredSignature);
+  }
+
+  let (balance_pda, balance_bump) = balance_pda_seeds(owner_account.key, program_id);
+  if *balance_account.key != balance_pda {
+    return Err(BiddingError::InvalidBalanceAccount.into());
+  }
+
+  if balance_account.data_len() == 0 {
+    create_pda_account(
+      program_id,
+      owner_account,
+      balance_account,
+      system_program,
+      BALANCE_ACCOUNT_SIZE,
+      &[b"balance", owner_account.key.as_ref(), &[balance_bump]],
+    )?;
+
+    let balance = Balance { owner: *owner_account.key };
+    store_account(balance_account, &balance)?;
+  }
+
+  check_balance_account(program_id, balance_account, owner_account.key)?;
+
+  invoke(
+    &system_instruction::transfer(owner_account.key, balance_account.key, amount),
+    &[owner_account.clone(), balance_account.clone(), system_program.clone()],
+  )
+}
@@ -0,0 +1,267 @@
+use solana_program::{
+  account_info::{next_account_info, AccountInfo},
+  entrypoint::ProgramResult,
+  pubkey::Pubkey,
+  program_error::ProgramError,
+  sysvar::{clock::Clock, Sysvar},
+};
+use crate::state::{GameState, GameStatus, PlayerState, Settlement, SETTLEMENT_ACCOUNT_SIZE};
+use crate::error::BiddingError;
+use crate::layout::{load_account, store_account};
+use crate::ledger::find_bids;
+use crate::zero_copy::{load_view_mut, GameStateView};
+use crate::instructions::place_bid::record_bid;
+use crate::instructions::reveal_bid::{load_commitment, refund_deposit};
+use crate::utils::{
+  game_pda_seeds,
+  player_pda_seeds,
+  ledger_pda_seeds,
+  commitment_pda_seeds,
+  settlement_pda_seeds,
+  treasury_pda_seeds,
+  find_account,
+  create_pda_account,
+  check_vault,
+  bidding_closes_at,
+  next_token_accounts,
+  pay_from_vault,
+  TokenAccounts
+};
+
+pub fn end_game<'a, 'b: 'a>(
+  program_id: &Pubkey,
+  accounts: &'a [AccountInfo<'b>],
+  game_id: u64,
+) -> ProgramResult {
+  let accounts_iter = &mut accounts.iter();
+  let system_program = next_account_info(accounts_iter)?;
+  let treasury_account = next_account_info(accounts_iter)?;
+  let game_account = next_account_info(accounts_iter)?;
+  let payer_account = next_account_info(accounts_iter)?;
+  let settlement_account = next_account_info(accounts_iter)?;
+  let vault_account = next_account_info(accounts_iter)?;
+
+  let (game_pda, _) = game_pda_seeds(game_id, program_id);
+  if *game_account.key != game_pda || game_account.owner != program_id {
+    return Err(BiddingError::InvalidGameAccount.into());
+  }
+
+  let mut game_state = load_account::<GameState>(program_id, game_account)?;
+
+  match game_state.status {
+    GameStatus::Active => {},
+    GameStatus::Settling => return Err(BiddingError::SettlementInProgress.into()),
+    GameStatus::Ended => return Err(BiddingError::GameEnded.into()),
+  }
+
+  let current_time = Clock::get()?.unix_timestamp as u64;
+
+  if current_time <= bidding_closes_at(&game_state) {
+    return Err(BiddingError::GameStillActive.into());
+  }
+
+  let (treasury_pda, _) = treasury_pda_seeds(program_id);
+  if *treasury_account.key != treasury_pda || treasury_account.owner != program_id {
+    return Err(BiddingError::InvalidTreasuryAccount.into());
+  }
+
+  check_vault(program_id, game_id, &game_state.mint, vault_account)?;
+
+  let token_accounts = next_token_accounts(&game_state.mint, accounts_iter)?;
+  let winner_token_accounts = match token_accounts {
+    Some(token_accounts) => Some(token_accounts.with_token_account(next_account_info(accounts_iter)?)),
+    None => None,
+  };
+  let fee_destination = token_accounts.map_or(treasury_account, |token_accounts| token_accounts.token_account);
+
+  if let Some(winner) = game_state.revealed_leader() {
+    record_revealed_winner(
+      program_id,
+      system_program,
+      payer_account,
+      game_account,
+      vault_account,
+      &winner,
+      winner_token_accounts,
+      accounts,
+    )?;
+    game_state = load_account::<GameState>(program_id, game_account)?;
+  }
+
+  if game_state.total_bids == 1 {
+    return Err(BiddingError::UncontestedGame.into());
+  }
+
+  let (settlement_pda, settlement_bump) = settlement_pda_seeds(game_id, program_id);
+  if *settlement_account.key != settlement_pda {
+    return Err(BiddingError::InvalidSettlementAccount.into());
+  }
+
+  let forfeited_deposits = game_state.escrowed_deposits;
+  game_state.prize_pool += forfeited_deposits;
+  game_state.escrowed_deposits = 0;
+
+  let total_bids = game_state.total_bids;
+  let royalty_window = game_state.rules.royalty_window;
+  let first_window_bid = total_bids.saturating_sub(royalty_window) + 1;
+  let window_bids = find_bids(program_id, game_id, first_window_bid, total_bids, accounts)?;
+  let window_amount = window_bids.iter().map(|bid| bid.amount.get()).sum::<u64>();
+  let royalty_amount = if total_bids > royalty_window { window_bids[1].amount.get() } else { 0 };
+  let platform_fee = ((window_amount as u128 * game_state.platform_fee_percentage as u128 / 100) as u64)
+    .min(window_amount - royalty_amount);
+
+  pay_from_vault(
+    program_id,
+    game_id,
+    game_account,
+    vault_account,
+    fee_destination,
+    &treasury_pda,
+    token_accounts,
+    platform_fee,
+  )?;
+
+  if total_bids <= royalty_window {
+    let amount = game_state.prize_pool - platform_fee;
+    return pay_winner(program_id, game_account, vault_account, winner_token_accounts, accounts, &mut game_state, amount);
+  }
+
+  if !payer_account.is_signer {
+    return Err(ProgramError::MissingRequiredSignature);
+  }
+
+  create_pda_account(
+    program_id,
+    payer_account,
+    settlement_account,
+    system_program,
+    SETTLEMENT_ACCOUNT_SIZE,
+    &[b"settlement", &game_id.to_le_bytes(), &[settlement_bump]],
+  )?;
+
+  let eligible_bids = total_bids - royalty_window;
+
+  let settlement = Settlement {
+    game_id,
+    eligible_bids,
+    next_index: 0,
+    royalty_amount,
+    total_weight: eligible_bids * (eligible_bids + 1) / 2,
+    total_bid_amount: game_state.prize_pool - forfeited_deposits - window_amount,
+    platform_fee,
+    total_entitled: 0,
+    payer: *payer_account.key,
+  };
+
+  game_state.status = GameStatus::Settling;
+
+  store_account(settlement_account, &settlement)?;
+  store_account(game_account, &game_state)?;
+
+  Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn record_revealed_winner<'a, 'b: 'a>(
+  program_id: &Pubkey,
+  system_program: &'a AccountInfo<'b>,
+  payer_account: &'a AccountInfo<'b>,
+  game_account: &'a AccountInfo<'b>,
+  vault_account: &'a AccountInfo<'b>,
+  winner: &Pubkey,
+  token_accounts: Option<TokenAccounts<'a, 'b>>,
+  accounts: &'a [AccountInfo<'b>],
+) -> ProgramResult {
+  if !payer_account.is_signer {
+    return Err(ProgramError::MissingRequiredSignature);
+  }
+
+  let mut game = load_view_mut::<GameStateView>(program_id, game_account)?;
+  let game_id = game.game_id.get();
+
+  let (commitment_pda, _) = commitment_pda_seeds(game_id, winner, program_id);
+  let commitment_account = find_account(&commitment_pda, accounts)
+    .map_err(|_| BiddingError::InvalidCommitmentAccount)?;
+  let commitment = load_commitment(program_id, game_id, commitment_account, winner)?;
+
+  let winner_account = find_account(winner, accounts).map_err(|_| BiddingError::NoWinnerFound)?;
+
+  let (ledger_pda, _) = ledger_pda_seeds(game_id, program_id);
+  let ledger_account = find_account(&ledger_pda, accounts)
+    .map_err(|_| BiddingError::LedgerAccountNotFound)?;
+
+  let (player_pda, _) = player_pda_seeds(game_id, winner, game.total_bids.get() + 1, program_id);
+  let player_account = find_account(&player_pda, accounts)
+    .map_err(|_| BiddingError::PlayerAccountNotFound)?;
+
+  let amount = game.highest_bid.get();
+  let revealed_at = game.last_bid_time.get();
+  let escrowed_deposits = game.escrowed_deposits.get() - commitment.deposit;
+  game.escrowed_deposits.set(escrowed_deposits);
+
+  record_bid(
+    program_id,
+    system_program,
+    payer_account,
+    winner,
+    ledger_account,
+    player_account,
+    &mut game,
+    amount,
+    revealed_at,
+  )?;
+  drop(game);
+
+  refund_deposit(
+    program_id,
+    game_id,
+    game_account,
+    vault_account,
+    commitment_account,
+    winner_account,
+    token_accounts,
+    commitment.deposit - amount,
+  )
+}
+
+pub fn pay_winner<'a, 'b: 'a>(
+  program_id: &Pubkey,
+  game_account: &'a AccountInfo<'b>,
+  vault_account: &'a AccountInfo<'b>,
+  token_accounts: Option<TokenAccounts<'a, 'b>>,
+  accounts: &'a [AccountInfo<'b>],
+  game_state: &mut GameState,
+  amount: u64,
+) -> ProgramResult {
+  let game_id = game_state.game_id;
+  let winner = game_state.last_bidder;
+  let winner_destination = match token_accounts {
+    Some(token_accounts) => token_accounts.token_account,
+    None => find_account(&winner, accounts).map_err(|_| BiddingError::NoWinnerFound)?,
+  };
+
+  pay_from_vault(
+    program_id,
+    game_id,
+    game_account,
+    vault_account,
+    winner_destination,
+    &winner,
+    token_accounts,
+    amount,
+  )?;
+
+  let (winner_player_pda, _) = player_pda_seeds(game_id, &winner, game_state.total_bids, program_id);
+  let winner_player_account = find_account(&winner_player_pda, accounts)
+    .map_err(|_| BiddingError::PlayerAccountNotFound)?;
+  let mut player_state = load_account::<PlayerState>(program_id, winner_player_account)?;
+  player_state.safe = true;
+  player_state.royalty_earned += amount;
+  player_state.claimed = true;
+  store_account(winner_player_account, &player_state)?;
+
+  game_state.status = GameStatus::Ended;
+  store_account(game_account, game_state)?;
+
+  Ok(())
+}
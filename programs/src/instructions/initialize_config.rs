@@ -0,0 +1,114 @@
+use solana_program::{
+  account_info::{next_account_info, AccountInfo},
+  bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+  entrypoint::ProgramResult,
+  pubkey::Pubkey,
+  program_utils::limited_deserialize,
+  program_error::ProgramError,
+};
+use crate::instructions::ConfigParams;
+use crate::state::{PlatformConfig, BPS_DENOMINATOR, CONFIG_ACCOUNT_SIZE};
+use crate::error::BiddingError;
+use crate::layout::store_account;
+use crate::utils::{config_pda_seeds, treasury_pda_seeds, rules_within_bounds, create_pda_account};
+
+pub fn initialize_config(
+  program_id: &Pubkey,
+  accounts: &[AccountInfo],
+  params: ConfigParams,
+) -> ProgramResult {
+  let accounts_iter = &mut accounts.iter();
+  let config_account = next_account_info(accounts_iter)?;
+  let authority_account = next_account_info(accounts_iter)?;
+  let system_program = next_account_info(accounts_iter)?;
+  let treasury_account = next_account_info(accounts_iter)?;
+  let program_data_account = next_account_info(accounts_iter)?;
+
+  if !authority_account.is_signer {
+    return Err(ProgramError::MissingRequiredSignature);
+  }
+
+  if upgrade_authority(program_id, program_data_account)? != Some(*authority_account.key) {
+    return Err(BiddingError::InvalidAuthority.into());
+  }
+
+  let (config_pda, config_bump) = config_pda_seeds(program_id);
+  if *config_account.key != config_pda {
+    return Err(BiddingError::InvalidConfigAccount.into());
+  }
+
+  let (treasury_pda, treasury_bump) = treasury_pda_seeds(program_id);
+  if *treasury_account.key != treasury_pda {
+    return Err(BiddingError::InvalidTreasuryAccount.into());
+  }
+
+  validate_config_params(&params)?;
+
+  create_pda_account(
+    program_id,
+    authority_account,
+    config_account,
+    system_program,
+    CONFIG_ACCOUNT_SIZE,
+    &[b"config", &[config_bump]],
+  )?;
+
+  create_pda_account(
+    program_id,
+    authority_account,
+    treasury_account,
+    system_program,
+    0,
+    &[b"treasury", &[treasury_bump]],
+  )?;
+
+  let config = PlatformConfig {
+    authority: *authority_account.key,
+    platform_fee_percentage: params.platform_fee_percentage,
+    min_initial_bid: params.min_initial_bid,
+    default_rules: params.default_rules,
+    min_rules: params.min_rules,
+    max_rules: params.max_rules,
+  };
+
+  store_account(config_account, &config)?;
+
+  Ok(())
+}
+
+fn upgrade_authority(
+  program_id: &Pubkey,
+  program_data_account: &AccountInfo,
+) -> Result<Option<Pubkey>, ProgramError> {
+  if *program_data_account.key != bpf_loader_upgradeable::get_program_data_address(program_id)
+    || *program_data_account.owner != bpf_loader_upgradeable::id()
+  {
+    return Err(BiddingError::InvalidProgramDataAccount.into());
+  }
+
+  let data = program_data_account.try_borrow_data()?;
+  let metadata_len = UpgradeableLoaderState::size_of_programdata_metadata();
+  let state = data
+    .get(..metadata_len)
+    .and_then(|metadata| limited_deserialize(metadata, metadata_len as u64).ok());
+
+  match state {
+    Some(UpgradeableLoaderState::ProgramData { upgrade_authority_address, .. }) => Ok(upgrade_authority_address),
+    _ => Err(BiddingError::InvalidProgramDataAccount.into()),
+  }
+}
+
+pub fn validate_config_params(params: &ConfigParams) -> Result<(), BiddingError> {
+  if params.platform_fee_percentage > 50
+    || params.min_initial_bid == 0
+    || params.min_rules.bid_multiplier_bps <= BPS_DENOMINATOR
+    || params.min_rules.bid_timeout == 0
+    || params.min_rules.royalty_window < 3
+    || params.min_rules.max_bids_per_wallet == 0
+    || !rules_within_bounds(&params.default_rules, &params.min_rules, &params.max_rules)
+  {
+    return Err(BiddingError::InvalidConfig);
+  }
+
+  Ok(())
+}
@@ -0,0 +1,265 @@
+use borsh::BorshDeserialize;
+use solana_program::{
+  account_info::{next_account_info, AccountInfo},
+  entrypoint::ProgramResult,
+  pubkey::Pubkey,
+  program_error::ProgramError,
+  sysvar::{rent::Rent, Sysvar},
+};
+use std::slice::Iter;
+use crate::state::{
+  AccountKind,
+  BidV1,
+  GameState,
+  GameStateV1,
+  PlayerStateV1,
+  PlayerStats,
+  PlayerStatsV1,
+  BID_V1_ACCOUNT_SIZE,
+  GAME_ACCOUNT_SIZE,
+  GAME_V1_ACCOUNT_SIZE,
+  PLAYER_V1_ACCOUNT_SIZE
+};
+use crate::error::BiddingError;
+use crate::layout::{load_account, store_account, AccountLayout};
+use crate::ledger::{create_ledger, ledger_len, load_bids, append_bid};
+use crate::zero_copy::BidView;
+use crate::utils::{
+  game_pda_seeds,
+  vault_pda_seeds,
+  ledger_pda_seeds,
+  bid_pda_seeds,
+  player_pda_seeds,
+  create_pda_account,
+  fund_account,
+  transfer_from_pda,
+  close_pda
+};
+
+pub fn migrate_account(
+  program_id: &Pubkey,
+  accounts: &[AccountInfo],
+) -> ProgramResult {
+  let accounts_iter = &mut accounts.iter();
+  let system_program = next_account_info(accounts_iter)?;
+  let payer_account = next_account_info(accounts_iter)?;
+  let account = next_account_info(accounts_iter)?;
+
+  if !payer_account.is_signer {
+    return Err(ProgramError::MissingRequiredSignature);
+  }
+
+  if account.owner != program_id {
+    return Err(BiddingError::InvalidAccountOwner.into());
+  }
+
+  match account.data_len() {
+    GAME_V1_ACCOUNT_SIZE => {
+      return migrate_game(program_id, system_program, payer_account, account, accounts_iter);
+    },
+    PLAYER_V1_ACCOUNT_SIZE | BID_V1_ACCOUNT_SIZE => {
+      return Err(BiddingError::InvalidAccountType.into());
+    },
+    _ => {},
+  }
+
+  let (kind, version) = match account.try_borrow_data()?[..] {
+    [kind, version, ..] => (kind, version),
+    _ => return Err(BiddingError::InvalidAccountType.into()),
+  };
+
+  match kind {
+    kind if kind == AccountKind::Game as u8 => {
+      migrate_game(program_id, system_program, payer_account, account, accounts_iter)
+    },
+    kind if kind == AccountKind::Stats as u8 => {
+      let ledger_account = next_account_info(accounts_iter)?;
+      let stats = migrate_player_stats(program_id, account, ledger_account, version)?;
+      rewrite_account(program_id, system_program, payer_account, account, &stats)
+    },
+    _ => Err(BiddingError::InvalidAccountType.into()),
+  }
+}
+
+fn migrate_game<'a>(
+  program_id: &Pubkey,
+  system_program: &AccountInfo<'a>,
+  payer_account: &AccountInfo<'a>,
+  game_account: &AccountInfo<'a>,
+  accounts_iter: &mut Iter<AccountInfo<'a>>,
+) -> ProgramResult {
+  let vault_account = next_account_info(accounts_iter)?;
+  let ledger_account = next_account_info(accounts_iter)?;
+
+  let game_id = if game_account.data_len() == GAME_V1_ACCOUNT_SIZE {
+    upgrade_game_v1(program_id, system_program, payer_account, game_account, vault_account, ledger_account)?
+  } else if accounts_iter.len() > 0 {
+    load_account::<GameState>(program_id, game_account)?.game_id
+  } else {
+    return Err(BiddingError::AccountAlreadyMigrated.into());
+  };
+
+  backfill_ledger(program_id, system_program, ledger_account, game_id, accounts_iter.as_slice())
+}
+
+fn upgrade_game_v1<'a>(
+  program_id: &Pubkey,
+  system_program: &AccountInfo<'a>,
+  payer_account: &AccountInfo<'a>,
+  game_account: &AccountInfo<'a>,
+  vault_account: &AccountInfo<'a>,
+  ledger_account: &AccountInfo<'a>,
+) -> Result<u64, ProgramError> {
+  let game_v1 = GameStateV1::deserialize(&mut &game_account.try_borrow_data()?[..])
+    .map_err(|_| ProgramError::InvalidAccountData)?;
+  let game_id = game_v1.game_id;
+
+  let (game_pda, _) = game_pda_seeds(game_id, program_id);
+  if *game_account.key != game_pda {
+    return Err(BiddingError::InvalidGameAccount.into());
+  }
+  let (vault_pda, vault_bump) = vault_pda_seeds(game_id, program_id);
+  if *vault_account.key != vault_pda {
+    return Err(BiddingError::InvalidVaultAccount.into());
+  }
+
+  let rent = Rent::get()?;
+  let pot = game_account.lamports().saturating_sub(rent.minimum_balance(GAME_V1_ACCOUNT_SIZE));
+  let top_up = rent.minimum_balance(GAME_ACCOUNT_SIZE).saturating_sub(game_account.lamports() - pot);
+
+  create_pda_account(
+    program_id,
+    payer_account,
+    vault_account,
+    system_program,
+    0,
+    &[b"vault", &game_id.to_le_bytes(), &[vault_bump]],
+  )?;
+  create_ledger(program_id, payer_account, ledger_account, system_program, game_id)?;
+  fund_account(program_id, payer_account, game_account, system_program, top_up)?;
+
+  transfer_from_pda(game_account, vault_account, pot)?;
+  game_account.realloc(GAME_ACCOUNT_SIZE, false)?;
+  store_account(game_account, &game_v1.upgrade())?;
+
+  Ok(game_id)
+}
+
+fn backfill_ledger<'a>(
+  program_id: &Pubkey,
+  system_program: &AccountInfo<'a>,
+  ledger_account: &AccountInfo<'a>,
+  game_id: u64,
+  bid_accounts: &[AccountInfo<'a>],
+) -> ProgramResult {
+  if bid_accounts.len() % 3 != 0 {
+    return Err(ProgramError::NotEnoughAccountKeys);
+  }
+
+  for chunk in bid_accounts.chunks(3) {
+    let bid_account = &chunk[0];
+    let player_account = &chunk[1];
+    let bidder_account = &chunk[2];
+
+    let bid_number = ledger_len(program_id, ledger_account, game_id)? + 1;
+    let (bid_pda, _) = bid_pda_seeds(game_id, bid_number, program_id);
+    if *bid_account.key != bid_pda
+      || bid_account.owner != program_id
+      || bid_account.data_len() != BID_V1_ACCOUNT_SIZE
+    {
+      return Err(BiddingError::InvalidBidAccount.into());
+    }
+
+    let bid = BidV1::deserialize(&mut &bid_account.try_borrow_data()?[..])
+      .map_err(|_| ProgramError::InvalidAccountData)?;
+    if *bidder_account.key != bid.bidder {
+      return Err(BiddingError::InvalidPayerAccount.into());
+    }
+
+    let (player_pda, _) = player_pda_seeds(game_id, &bid.bidder, bid_number, program_id);
+    if *player_account.key != player_pda
+      || player_account.owner != program_id
+      || player_account.data_len() != PLAYER_V1_ACCOUNT_SIZE
+    {
+      return Err(BiddingError::InvalidPlayerAccount.into());
+    }
+
+    let player_state = PlayerStateV1::deserialize(&mut &player_account.try_borrow_data()?[..])
+      .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    append_bid(
+      program_id,
+      bid_account,
+      ledger_account,
+      system_program,
+      game_id,
+      bid_number,
+      BidView {
+        bidder: bid.bidder,
+        amount: bid.amount.into(),
+        timestamp: bid.timestamp.into(),
+        payer: bid.bidder,
+      },
+    )?;
+    rewrite_account(program_id, system_program, bid_account, player_account, &player_state.upgrade(bid.bidder))?;
+    close_pda(bid_account, bidder_account)?;
+  }
+
+  Ok(())
+}
+
+fn migrate_player_stats(
+  program_id: &Pubkey,
+  stats_account: &AccountInfo,
+  ledger_account: &AccountInfo,
+  version: u8,
+) -> Result<PlayerStats, ProgramError> {
+  match version {
+    PlayerStats::VERSION => Err(BiddingError::AccountAlreadyMigrated.into()),
+    1 => {
+      let stats = load_account::<PlayerStatsV1>(program_id, stats_account)?;
+      let payer = first_bid_payer(program_id, ledger_account, stats.game_id, &stats.player)?;
+      Ok(stats.upgrade(payer))
+    },
+    _ => Err(BiddingError::UnsupportedAccountVersion.into()),
+  }
+}
+
+fn first_bid_payer(
+  program_id: &Pubkey,
+  ledger_account: &AccountInfo,
+  game_id: u64,
+  player: &Pubkey,
+) -> Result<Pubkey, ProgramError> {
+  let (ledger_pda, _) = ledger_pda_seeds(game_id, program_id);
+  if *ledger_account.key != ledger_pda {
+    return Err(BiddingError::InvalidLedgerAccount.into());
+  }
+
+  let len = ledger_len(program_id, ledger_account, game_id)?;
+  let bids = load_bids(program_id, ledger_account, game_id, 1, len)?;
+
+  bids
+    .iter()
+    .find(|bid| bid.bidder == *player)
+    .map(|bid| bid.payer)
+    .ok_or(BiddingError::BidNotRecorded.into())
+}
+
+fn rewrite_account<'a, T: AccountLayout>(
+  program_id: &Pubkey,
+  system_program: &AccountInfo<'a>,
+  payer_account: &AccountInfo<'a>,
+  account: &AccountInfo<'a>,
+  value: &T,
+) -> ProgramResult {
+  let rent = Rent::get()?;
+  let top_up = rent.minimum_balance(T::SPACE).saturating_sub(account.lamports());
+
+  if top_up > 0 {
+    fund_account(program_id, payer_account, account, system_program, top_up)?;
+  }
+
+  account.realloc(T::SPACE, false)?;
+  store_account(account, value)
+}
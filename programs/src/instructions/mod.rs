@@ -1,19 +1,148 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+use crate::state::{GameMode, GameRules, PriceCurve};
 
 pub mod create_game;
 pub mod place_bid;
+pub mod end_game;
+pub mod settle_batch;
+pub mod claim_royalty;
+pub mod cancel_game;
+pub mod close_accounts;
+pub mod initialize_config;
+pub mod update_config;
+pub mod withdraw_fees;
+pub mod commit_bid;
+pub mod reveal_bid;
+pub mod quote_price;
+pub mod set_auto_bid;
+pub mod crank_auto_bid;
+pub mod cancel_auto_bid;
+pub mod deposit;
+pub mod withdraw;
+pub mod place_bid_from_balance;
+pub mod register_session;
+pub mod revoke_session;
+pub mod migrate_account;
+pub mod close_ledger;
 
 pub use create_game::*;
 pub use place_bid::*;
+pub use end_game::*;
+pub use settle_batch::*;
+pub use claim_royalty::*;
+pub use cancel_game::*;
+pub use close_accounts::*;
+pub use initialize_config::*;
+pub use update_config::*;
+pub use withdraw_fees::*;
+pub use commit_bid::*;
+pub use reveal_bid::*;
+pub use quote_price::*;
+pub use set_auto_bid::*;
+pub use crank_auto_bid::*;
+pub use cancel_auto_bid::*;
+pub use deposit::*;
+pub use withdraw::*;
+pub use place_bid_from_balance::*;
+pub use register_session::*;
+pub use revoke_session::*;
+pub use migrate_account::*;
+pub use close_ledger::*;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy)]
+pub struct ConfigParams {
+    pub platform_fee_percentage: u64,
+    pub min_initial_bid: u64,
+    pub default_rules: GameRules,
+    pub min_rules: GameRules,
+    pub max_rules: GameRules,
+}
 
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 pub enum BiddingInstruction {
     CreateGame {
         game_id: u64,
         initial_bid_amount: u64,
+        rules: Option<GameRules>,
+        mode: GameMode,
+        price_curve: Option<PriceCurve>,
+        ends_at: Option<u64>,
     },
     PlaceBid {
         bid_amount: u64,
         bid_count:u64,
     },
+    EndGame {
+        game_id: u64,
+    },
+    SettleBatch {
+        start: u64,
+        count: u64,
+    },
+    ClaimRoyalty {
+        game_id: u64,
+        bid_count: u64,
+    },
+    CancelGame {
+        game_id: u64,
+    },
+    CloseAccounts {
+        game_id: u64,
+    },
+    InitializeConfig {
+        params: ConfigParams,
+    },
+    UpdateConfig {
+        params: ConfigParams,
+        new_authority: Option<Pubkey>,
+    },
+    WithdrawFees {
+        amount: u64,
+    },
+    CommitBid {
+        game_id: u64,
+        commitment: [u8; 32],
+        deposit: u64,
+    },
+    RevealBid {
+        game_id: u64,
+        amount: u64,
+        salt: [u8; 32],
+    },
+    QuotePrice {
+        game_id: u64,
+    },
+    SetAutoBid {
+        game_id: u64,
+        max_budget: u64,
+    },
+    CrankAutoBid {
+        game_id: u64,
+        bid_count: u64,
+    },
+    CancelAutoBid {
+        game_id: u64,
+    },
+    Deposit {
+        amount: u64,
+    },
+    Withdraw {
+        amount: u64,
+    },
+    PlaceBidFromBalance {
+        game_id: u64,
+        bid_amount: u64,
+        bid_count: u64,
+    },
+    RegisterSession {
+        session_key: Pubkey,
+        spending_cap: u64,
+        expires_at: u64,
+    },
+    RevokeSession,
+    MigrateAccount,
+    CloseLedger {
+        game_id: u64,
+    },
 }
\ No newline at end of file
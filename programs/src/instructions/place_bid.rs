@@ -1,23 +1,31 @@
 use solana_program::{
   account_info::{next_account_info, AccountInfo},
   entrypoint::ProgramResult,
-  pubkey::Pubkey,
-  program::invoke_signed,
-  program::invoke,
-  system_instruction,
   program_error::ProgramError,
-  sysvar::{clock::Clock, rent::Rent, Sysvar},
+  pubkey::Pubkey,
+  sysvar::{clock::Clock, Sysvar},
+};
+use crate::state::{
+  GameMode,
+  GameStatus,
+  PlayerStats,
+  PLAYER_ACCOUNT_SIZE,
+  STATS_ACCOUNT_SIZE
 };
-use borsh::BorshSerialize;
-use crate::state::{Bid, GameState, PlayerState, BID_ACCOUNT_SIZE, PLAYER_ACCOUNT_SIZE};
 use crate::error::BiddingError;
+use crate::layout::{load_account, store_account};
+use crate::ledger::append_bid;
+use crate::zero_copy::{init_view, load_view_mut, BidView, GameStateView, PlayerStateView};
+use crate::instructions::place_bid_from_balance::bid_from_balance;
 use crate::utils::{
   player_pda_seeds, 
-  bid_pda_seeds, find_account, 
-  fetch_bid_history, 
-  transfer_from_pda, 
-  deserialize_game_state,
-  deserialize_player_state
+  stats_pda_seeds,
+  check_vault,
+  next_token_accounts,
+  deposit_to_vault,
+  required_bid,
+  extend_deadline,
+  create_pda_account
 };
 
 pub fn place_bid(
@@ -31,243 +39,274 @@ pub fn place_bid(
   let _platform_account = next_account_info(accounts_iter)?;
   let game_account = next_account_info(accounts_iter)?;
   let bidder_account = next_account_info(accounts_iter)?;
-  let new_bid_account = next_account_info(accounts_iter)?;
+  let ledger_account = next_account_info(accounts_iter)?;
   let new_player_account = next_account_info(accounts_iter)?;
+  let vault_account = next_account_info(accounts_iter)?;
+  let stats_account = next_account_info(accounts_iter)?;
 
-  if game_account.data_len() != std::mem::size_of::<GameState>() {
-    return Err(ProgramError::InvalidAccountData.into());
-  }
-
-  let mut game_state = deserialize_game_state(&game_account.data.borrow())?;
-
-  if game_state.game_ended {
-    return Err(BiddingError::GameEnded.into());
-  }
-
+  let mut game = load_view_mut::<GameStateView>(program_id, game_account)?;
   let current_time = Clock::get()?.unix_timestamp as u64;
 
-  if current_time - game_state.last_bid_time > 600 {
-    return end_game(program_id, game_state.game_id, accounts, &mut game_state);
-  }
-
-  if bid_amount < 2 * game_state.highest_bid {
-    return Err(BiddingError::InsufficientBidAmount.into());
-  }
+  check_bidding_open(&game, current_time)?;
 
-  let new_bid_count = game_state.total_bids + 1;
+  check_vault(program_id, game.game_id.get(), &game.mint, vault_account)?;
 
-  if bid_count != new_bid_count {
+  if bid_count != game.total_bids.get() + 1 {
     return Err(BiddingError::BidCountMismatch.into());
   } 
 
-  let (new_player_pda, new_player_bump) = player_pda_seeds(
-    game_state.game_id, 
-    bidder_account.key, 
-    new_bid_count, 
-    program_id
-  );
-  if *new_player_account.key != new_player_pda {
-    return Err(BiddingError::InvalidNewPlayerAccount.into());
-  }
-
-  let (new_bid_pda, new_bid_bump) = bid_pda_seeds(
-    game_state.game_id, 
-    new_bid_count, 
-    program_id
-  );
-  if *new_bid_account.key != new_bid_pda {
-    return Err(BiddingError::InvalidNewBidAccount.into());
-  }
-
-  let rent = Rent::get()?;
-  let player_rent = rent.minimum_balance(std::mem::size_of::<PlayerState>());
-  let bid_rent = rent.minimum_balance(std::mem::size_of::<Bid>());
+  if !bidder_account.is_signer {
+    let balance_account = next_account_info(accounts_iter)?;
+    let session_account = next_account_info(accounts_iter)?;
+    let session_key_account = next_account_info(accounts_iter)?;
 
-  invoke_signed(
-    &system_instruction::create_account(
-      bidder_account.key,
-      new_player_account.key,
-      player_rent,
-      PLAYER_ACCOUNT_SIZE as u64,
+    return bid_from_balance(
       program_id,
-    ),
-    &[bidder_account.clone(), new_player_account.clone(), system_program.clone()],
-    &[&[
-      b"player", 
-      &game_state.game_id.to_le_bytes(), 
-      bidder_account.key.as_ref(), 
-      &new_bid_count.to_le_bytes(), 
-      &[new_player_bump]
-    ]],
-  )?;
+      system_program,
+      bidder_account,
+      balance_account,
+      ledger_account,
+      new_player_account,
+      vault_account,
+      stats_account,
+      Some((session_account, session_key_account)),
+      &mut game,
+      bid_amount,
+      current_time,
+    );
+  }
 
-  invoke_signed(
-    &system_instruction::create_account(
-      bidder_account.key,
-      new_bid_account.key,
-      bid_rent,
-      BID_ACCOUNT_SIZE as u64,
-      program_id,
-    ),
-    &[bidder_account.clone(), new_bid_account.clone(), system_program.clone()],
-    &[&[
-      b"bid", 
-      &game_state.game_id.to_le_bytes(), 
-      &new_bid_count.to_le_bytes(), 
-      &[new_bid_bump]
-    ]],
+  let token_accounts = next_token_accounts(&game.mint, accounts_iter)?;
+  let beneficiary_account = accounts_iter.next().unwrap_or(bidder_account);
+  if !beneficiary_account.is_signer {
+    return Err(ProgramError::MissingRequiredSignature);
+  }
+  let beneficiary = beneficiary_account.key;
+
+  let received_amount = deposit_to_vault(
+    bidder_account,
+    vault_account,
+    system_program,
+    token_accounts,
+    bid_amount,
   )?;
 
-  game_state.highest_bid = bid_amount;
-  game_state.last_bid_time = current_time;
-  game_state.last_bidder = *bidder_account.key;
-  game_state.total_bids = new_bid_count;
-  game_state.prize_pool += bid_amount;
-
-  game_state.serialize(&mut &mut game_account.data.borrow_mut()[..])?;
+  accept_bid(
+    program_id,
+    system_program,
+    bidder_account,
+    beneficiary,
+    ledger_account,
+    new_player_account,
+    stats_account,
+    &mut game,
+    received_amount,
+    current_time,
+  )
+}
 
-  let new_player_state = PlayerState {
-    total_bid_amount: bid_amount,
-    safe: false,
-    royalty_earned: 0,
-    bid_count: new_bid_count,
-  };
+pub fn check_bidding_open(game: &GameStateView, current_time: u64) -> ProgramResult {
+  if game.status()? != GameStatus::Active {
+    return Err(BiddingError::GameEnded.into());
+  }
 
-  new_player_state.serialize(&mut &mut new_player_account.data.borrow_mut()[..])?;
- 
-  let new_bid = Bid {
-    bidder: *bidder_account.key,
-    amount: bid_amount,
-    timestamp: current_time,
-  };
+  let mode = game.mode()?;
 
-  new_bid.serialize(&mut &mut new_bid_account.data.borrow_mut()[..])?;
+  if mode == GameMode::Sealed {
+    return Err(BiddingError::InvalidGameMode.into());
+  }
 
-  invoke(
-    &system_instruction::transfer(
-      bidder_account.key,
-      game_account.key,
-      bid_amount,
-    ),
-    &[bidder_account.clone(), game_account.clone(), system_program.clone()],
-  )?;
+  if current_time > game.deadline.get() || (mode == GameMode::Dutch && game.total_bids.get() > 1) {
+    return Err(BiddingError::BiddingClosed.into());
+  }
 
   Ok(())
 }
 
-pub fn end_game<'a, 'b: 'a>(
-  program_id:  &Pubkey,
-  game_id: u64,
-  accounts: &'a [AccountInfo<'b>],
-  game_state: &mut GameState,
+#[allow(clippy::too_many_arguments)]
+pub fn accept_bid<'a>(
+  program_id: &Pubkey,
+  system_program: &AccountInfo<'a>,
+  payer_account: &AccountInfo<'a>,
+  bidder: &Pubkey,
+  ledger_account: &AccountInfo<'a>,
+  new_player_account: &AccountInfo<'a>,
+  stats_account: &AccountInfo<'a>,
+  game: &mut GameStateView,
+  amount: u64,
+  current_time: u64,
 ) -> ProgramResult {
-  let accounts_iter = &mut accounts.iter();
-  let _system_program = next_account_info(accounts_iter)?;
-  let platform_account = next_account_info(accounts_iter)?;
-  let game_account = next_account_info(accounts_iter)?;
-
-  let bid_history: Vec<Bid> = fetch_bid_history(program_id, game_state.game_id, game_state.total_bids, accounts)?;
+  if amount < required_bid(game, current_time)? {
+    return Err(BiddingError::InsufficientBidAmount.into());
+  }
 
-  if bid_history.len() < 5 {
-    let total_amount = game_state.prize_pool;
-    let platform_fee = total_amount * 10 / 100;
-    let remaining_prize = total_amount - platform_fee;
+  enforce_player_limits(
+    program_id,
+    system_program,
+    payer_account,
+    stats_account,
+    game,
+    bidder,
+    current_time,
+  )?;
 
-    let last_bid = bid_history.last().ok_or(BiddingError::NoBidsFound)?;
-    let winner_account = find_account(&last_bid.bidder, accounts)?;
+  record_bid(
+    program_id,
+    system_program,
+    payer_account,
+    bidder,
+    ledger_account,
+    new_player_account,
+    game,
+    amount,
+    current_time,
+  )?;
 
-    transfer_from_pda(game_account, platform_account, platform_fee)?;
+  match game.mode()? {
+    GameMode::Dutch => game.deadline.set(current_time),
+    _ => extend_deadline(game, current_time),
+  }
 
-    transfer_from_pda(game_account, winner_account, remaining_prize)?;
+  Ok(())
+}
 
-    game_state.game_ended = true;
-    game_state.serialize(&mut &mut game_account.data.borrow_mut()[..])?;
+fn enforce_player_limits<'a>(
+  program_id: &Pubkey,
+  system_program: &AccountInfo<'a>,
+  payer_account: &AccountInfo<'a>,
+  stats_account: &AccountInfo<'a>,
+  game: &GameStateView,
+  bidder: &Pubkey,
+  current_time: u64,
+) -> ProgramResult {
+  let rules = game.rules;
+  let game_id = game.game_id.get();
 
-    return Ok(());
+  if rules.no_self_outbid.get() && *bidder == game.last_bidder {
+    return Err(BiddingError::SelfOutbid.into());
   }
 
-  let last_5_bids: Vec<u64> = bid_history.iter().rev().take(5).map(|bid| bid.amount).collect();
-  let platform_fee = last_5_bids.iter().sum::<u64>() * game_state.platform_fee_percentage / 100;
-
-  let royalty_amount = bid_history[bid_history.len() - 4].amount;
-
-  cal_royalties(&bid_history, royalty_amount, program_id, game_id, game_state.total_bids, game_account, accounts)?;
-   
-  let last_bid = bid_history.last().ok_or(BiddingError::NoWinnerFound)?;
-  let winner_account = find_account(&last_bid.bidder, accounts)?;
+  let (stats_pda, stats_bump) = stats_pda_seeds(game_id, bidder, program_id);
+  if *stats_account.key != stats_pda {
+    return Err(BiddingError::InvalidStatsAccount.into());
+  }
 
-  transfer_from_pda(game_account, platform_account, platform_fee)?;
+  let mut stats = if stats_account.data_len() == 0 {
+    create_pda_account(
+      program_id,
+      payer_account,
+      stats_account,
+      system_program,
+      STATS_ACCOUNT_SIZE,
+      &[
+        b"stats",
+        &game_id.to_le_bytes(),
+        bidder.as_ref(),
+        &[stats_bump]
+      ],
+    )?;
+
+    PlayerStats {
+      game_id,
+      player: *bidder,
+      bid_count: 0,
+      last_bid_time: 0,
+      payer: *payer_account.key,
+    }
+  } else {
+    if stats_account.owner != program_id {
+      return Err(BiddingError::InvalidStatsAccount.into());
+    }
+
+    load_account::<PlayerStats>(program_id, stats_account)
+      .map_err(|_| BiddingError::InvalidStatsAccount)?
+  };
 
-  let current_balance = **game_account.try_borrow_lamports()?;
-  let rent = Rent::get()?;
-  let rent_exempt_balance = rent.minimum_balance(game_account.data_len());
-  let amount = current_balance.checked_sub(rent_exempt_balance)
-    .ok_or(BiddingError::InsufficientFunds)?;
+  if stats.bid_count >= rules.max_bids_per_wallet.get() {
+    return Err(BiddingError::BidLimitReached.into());
+  }
 
-  transfer_from_pda(game_account, winner_account, amount)?;
-  
-  let mut player_state =  deserialize_player_state(&winner_account.data.borrow())?;
-  player_state.safe = true; 
-  player_state.royalty_earned += amount; 
-  player_state.serialize(&mut &mut winner_account.data.borrow_mut()[..])?;
+  if stats.bid_count > 0 && current_time < stats.last_bid_time.saturating_add(rules.bid_cooldown.get()) {
+    return Err(BiddingError::BidCooldownActive.into());
+  }
 
-  game_state.game_ended = true;
-  game_state.serialize(&mut &mut game_account.data.borrow_mut()[..])?;
+  stats.bid_count += 1;
+  stats.last_bid_time = current_time;
+  store_account(stats_account, &stats)?;
 
   Ok(())
 }
 
-fn cal_royalties<'a, 'b: 'a>(
-  bid_history: &[Bid],
-  royalty_amount: u64,
+#[allow(clippy::too_many_arguments)]
+pub fn record_bid<'a>(
   program_id: &Pubkey,
-  game_id: u64,
-  _total_bids: u64,
-  game_account: &'a AccountInfo<'b>,
-  accounts: &'a [AccountInfo<'b>],
+  system_program: &AccountInfo<'a>,
+  payer_account: &AccountInfo<'a>,
+  bidder: &Pubkey,
+  ledger_account: &AccountInfo<'a>,
+  new_player_account: &AccountInfo<'a>,
+  game: &mut GameStateView,
+  amount: u64,
+  current_time: u64,
 ) -> ProgramResult {
-  let eligible_bidders = &bid_history[0..bid_history.len() - 5];
-  let total_bidders: usize = eligible_bidders.len();
-
-  let mut total_weight = 0;
-  let mut total_bid_amount = 0;
-  for (i, bid) in eligible_bidders.iter().enumerate() {
-      total_weight += total_bidders as u64 - i as u64; 
-      total_bid_amount += bid.amount; 
-  }
-  let mut remaining_royalty = royalty_amount;
-  for (i, bid) in eligible_bidders.iter().enumerate() {
-    let weight = total_bidders as u64 - i as u64;
-    let share = (weight * bid.amount) as u64;
-    let royalty_share = (share * royalty_amount) / (total_weight * total_bid_amount);
-    let total_share = bid.amount + royalty_share;
-
-    transfer_royalty(bid.bidder, total_share, game_account, accounts)?;
-
-    let bid_counts = (i as u64) + 1;
-    let (player_pda, _player_bump) = player_pda_seeds(game_id, &bid.bidder, bid_counts, program_id);
-    let player_account = find_account(&player_pda, accounts)?;
-    let mut player_state =  deserialize_player_state(&player_account.data.borrow())?;
-
-    player_state.safe = true; 
-    player_state.royalty_earned += royalty_share; 
-    player_state.serialize(&mut &mut player_account.data.borrow_mut()[..])?;
-    remaining_royalty = remaining_royalty.saturating_sub(royalty_share);
+  let game_id = game.game_id.get();
+  let new_bid_count = game.total_bids.get() + 1;
+
+  let (new_player_pda, new_player_bump) = player_pda_seeds(
+    game_id, 
+    bidder, 
+    new_bid_count, 
+    program_id
+  );
+  if *new_player_account.key != new_player_pda {
+    return Err(BiddingError::InvalidNewPlayerAccount.into());
   }
-  Ok(())
-}
 
-fn transfer_royalty<'a, 'b: 'a>(
-  bidder: Pubkey,
-  amount: u64,
-  game_account: &'a AccountInfo<'b>,
-  accounts: &'a [AccountInfo<'b>],
-) -> ProgramResult {
-  let bidder_account = find_account(&bidder, accounts)
-    .map_err(|_| BiddingError::BidderAccountNotFound)?;  
+  create_pda_account(
+    program_id,
+    payer_account,
+    new_player_account,
+    system_program,
+    PLAYER_ACCOUNT_SIZE,
+    &[
+      b"player", 
+      &game_id.to_le_bytes(), 
+      bidder.as_ref(), 
+      &new_bid_count.to_le_bytes(), 
+      &[new_player_bump]
+    ],
+  )?;
 
-  transfer_from_pda(game_account, bidder_account, amount)
-    .map_err(|_| BiddingError::RoyaltyTransferFailed)?;  
+  append_bid(
+    program_id,
+    payer_account,
+    ledger_account,
+    system_program,
+    game_id,
+    new_bid_count,
+    BidView {
+      bidder: *bidder,
+      amount: amount.into(),
+      timestamp: current_time.into(),
+      payer: *payer_account.key,
+    },
+  )?;
+
+  game.highest_bid.set(amount);
+  game.last_bid_time.set(current_time);
+  game.last_bidder = *bidder;
+  game.total_bids.set(new_bid_count);
+  game.prize_pool.set(game.prize_pool.get() + amount);
+
+  *init_view::<PlayerStateView>(new_player_account)? = PlayerStateView {
+    total_bid_amount: amount.into(),
+    safe: false.into(),
+    royalty_earned: 0.into(),
+    bid_count: new_bid_count.into(),
+    claimed: false.into(),
+    bidder: *bidder,
+    payer: *payer_account.key,
+  };
 
   Ok(())
-}
\ No newline at end of file
+}
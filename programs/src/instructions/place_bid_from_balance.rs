@@ -0,0 +1,177 @@
+use solana_program::{
+  account_info::{next_account_info, AccountInfo},
+  entrypoint::ProgramResult,
+  pubkey::Pubkey,
+  program_error::ProgramError,
+  sysvar::{clock::Clock, rent::Rent, Sysvar},
+};
+use crate::state::{Session, PLAYER_ACCOUNT_SIZE, STATS_ACCOUNT_SIZE};
+use crate::error::BiddingError;
+use crate::layout::{load_account, store_account};
+use crate::ledger::ledger_growth_rent;
+use crate::zero_copy::{load_view_mut, GameStateView};
+use crate::instructions::place_bid::{check_bidding_open, accept_bid};
+use crate::utils::{
+  game_pda_seeds,
+  session_pda_seeds,
+  check_vault,
+  check_balance_account,
+  available_lamports,
+  transfer_from_pda,
+  is_native_mint
+};
+
+pub fn place_bid_from_balance(
+  program_id: &Pubkey,
+  accounts: &[AccountInfo],
+  game_id: u64,
+  bid_amount: u64,
+  bid_count: u64,
+) -> ProgramResult {
+  let accounts_iter = &mut accounts.iter();
+  let system_program = next_account_info(accounts_iter)?;
+  let game_account = next_account_info(accounts_iter)?;
+  let bidder_account = next_account_info(accounts_iter)?;
+  let balance_account = next_account_info(accounts_iter)?;
+  let ledger_account = next_account_info(accounts_iter)?;
+  let new_player_account = next_account_info(accounts_iter)?;
+  let vault_account = next_account_info(accounts_iter)?;
+  let stats_account = next_account_info(accounts_iter)?;
+
+  let (game_pda, _) = game_pda_seeds(game_id, program_id);
+  if *game_account.key != game_pda || game_account.owner != program_id {
+    return Err(BiddingError::InvalidGameAccount.into());
+  }
+
+  let mut game = load_view_mut::<GameStateView>(program_id, game_account)?;
+  let current_time = Clock::get()?.unix_timestamp as u64;
+
+  check_bidding_open(&game, current_time)?;
+  check_vault(program_id, game_id, &game.mint, vault_account)?;
+
+  if bid_count != game.total_bids.get() + 1 {
+    return Err(BiddingError::BidCountMismatch.into());
+  }
+
+  let session_accounts = if bidder_account.is_signer {
+    None
+  } else {
+    Some((next_account_info(accounts_iter)?, next_account_info(accounts_iter)?))
+  };
+
+  bid_from_balance(
+    program_id,
+    system_program,
+    bidder_account,
+    balance_account,
+    ledger_account,
+    new_player_account,
+    vault_account,
+    stats_account,
+    session_accounts,
+    &mut game,
+    bid_amount,
+    current_time,
+  )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn bid_from_balance<'a>(
+  program_id: &Pubkey,
+  system_program: &AccountInfo<'a>,
+  bidder_account: &AccountInfo<'a>,
+  balance_account: &AccountInfo<'a>,
+  ledger_account: &AccountInfo<'a>,
+  new_player_account: &AccountInfo<'a>,
+  vault_account: &AccountInfo<'a>,
+  stats_account: &AccountInfo<'a>,
+  session_accounts: Option<(&AccountInfo<'a>, &AccountInfo<'a>)>,
+  game: &mut GameStateView,
+  bid_amount: u64,
+  current_time: u64,
+) -> ProgramResult {
+  if !is_native_mint(&game.mint) {
+    return Err(BiddingError::InvalidMint.into());
+  }
+
+  check_balance_account(program_id, balance_account, bidder_account.key)?;
+
+  let rent = Rent::get()?;
+  let stats_rent = match stats_account.data_len() {
+    0 => rent.minimum_balance(STATS_ACCOUNT_SIZE),
+    _ => 0,
+  };
+  let required_lamports = bid_amount
+    + rent.minimum_balance(PLAYER_ACCOUNT_SIZE)
+    + ledger_growth_rent(ledger_account)?
+    + stats_rent;
+
+  if required_lamports > available_lamports(balance_account)? {
+    return Err(BiddingError::InsufficientBalance.into());
+  }
+
+  if let Some((session_account, session_key_account)) = session_accounts {
+    charge_session(
+      program_id,
+      session_account,
+      session_key_account,
+      bidder_account.key,
+      required_lamports,
+      current_time,
+    )?;
+  }
+
+  transfer_from_pda(balance_account, vault_account, bid_amount)?;
+
+  accept_bid(
+    program_id,
+    system_program,
+    balance_account,
+    bidder_account.key,
+    ledger_account,
+    new_player_account,
+    stats_account,
+    game,
+    bid_amount,
+    current_time,
+  )
+}
+
+fn charge_session(
+  program_id: &Pubkey,
+  session_account: &AccountInfo,
+  session_key_account: &AccountInfo,
+  owner: &Pubkey,
+  amount: u64,
+  current_time: u64,
+) -> ProgramResult {
+  if !session_key_account.is_signer {
+    return Err(ProgramError::MissingRequiredSignature);
+  }
+
+  let (session_pda, _) = session_pda_seeds(owner, program_id);
+  if *session_account.key != session_pda || session_account.owner != program_id {
+    return Err(BiddingError::InvalidSessionAccount.into());
+  }
+
+  let mut session = load_account::<Session>(program_id, session_account)
+    .map_err(|_| BiddingError::InvalidSessionAccount)?;
+
+  if session.session_key != *session_key_account.key {
+    return Err(BiddingError::InvalidSessionAccount.into());
+  }
+
+  if current_time > session.expires_at {
+    return Err(BiddingError::SessionExpired.into());
+  }
+
+  let spent = session.spent.saturating_add(amount);
+  if spent > session.spending_cap {
+    return Err(BiddingError::SessionCapExceeded.into());
+  }
+
+  session.spent = spent;
+  store_account(session_account, &session)?;
+
+  Ok(())
+}
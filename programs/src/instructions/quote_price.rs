@@ -0,0 +1,38 @@
+use solana_program::{
+  account_info::{next_account_info, AccountInfo},
+  entrypoint::ProgramResult,
+  program::set_return_data,
+  pubkey::Pubkey,
+  sysvar::{clock::Clock, Sysvar},
+};
+use crate::state::GameStatus;
+use crate::error::BiddingError;
+use crate::zero_copy::{load_view, GameStateView};
+use crate::utils::{game_pda_seeds, required_bid};
+
+pub fn quote_price(
+  program_id: &Pubkey,
+  accounts: &[AccountInfo],
+  game_id: u64,
+) -> ProgramResult {
+  let accounts_iter = &mut accounts.iter();
+  let game_account = next_account_info(accounts_iter)?;
+
+  let (game_pda, _) = game_pda_seeds(game_id, program_id);
+  if *game_account.key != game_pda || game_account.owner != program_id {
+    return Err(BiddingError::InvalidGameAccount.into());
+  }
+
+  let game = load_view::<GameStateView>(program_id, game_account)?;
+
+  if game.status()? != GameStatus::Active {
+    return Err(BiddingError::GameEnded.into());
+  }
+
+  let current_time = Clock::get()?.unix_timestamp as u64;
+  let price = required_bid(&game, current_time)?;
+
+  set_return_data(&price.to_le_bytes());
+
+  Ok(())
+}
@@ -0,0 +1,64 @@
+use solana_program::{
+  account_info::{next_account_info, AccountInfo},
+  entrypoint::ProgramResult,
+  pubkey::Pubkey,
+  program_error::ProgramError,
+  sysvar::{clock::Clock, Sysvar},
+};
+use crate::state::{Session, SESSION_ACCOUNT_SIZE, MAX_SESSION_DURATION};
+use crate::error::BiddingError;
+use crate::layout::store_account;
+use crate::utils::{session_pda_seeds, create_pda_account};
+
+pub fn register_session(
+  program_id: &Pubkey,
+  accounts: &[AccountInfo],
+  session_key: Pubkey,
+  spending_cap: u64,
+  expires_at: u64,
+) -> ProgramResult {
+  let accounts_iter = &mut accounts.iter();
+  let system_program = next_account_info(accounts_iter)?;
+  let owner_account = next_account_info(accounts_iter)?;
+  let session_account = next_account_info(accounts_iter)?;
+
+  if !owner_account.is_signer {
+    return Err(ProgramError::MissingRequiredSignature);
+  }
+
+  let current_time = Clock::get()?.unix_timestamp as u64;
+
+  if expires_at <= current_time || expires_at - current_time > MAX_SESSION_DURATION {
+    return Err(BiddingError::InvalidSessionDuration.into());
+  }
+
+  let (session_pda, session_bump) = session_pda_seeds(owner_account.key, program_id);
+  if *session_account.key != session_pda {
+    return Err(BiddingError::InvalidSessionAccount.into());
+  }
+
+  if session_account.data_len() == 0 {
+    create_pda_account(
+      program_id,
+      owner_account,
+      session_account,
+      system_program,
+      SESSION_ACCOUNT_SIZE,
+      &[b"session", owner_account.key.as_ref(), &[session_bump]],
+    )?;
+  } else if session_account.owner != program_id {
+    return Err(BiddingError::InvalidSessionAccount.into());
+  }
+
+  let session = Session {
+    owner: *owner_account.key,
+    session_key,
+    spending_cap,
+    spent: 0,
+    expires_at,
+  };
+
+  store_account(session_account, &session)?;
+
+  Ok(())
+}
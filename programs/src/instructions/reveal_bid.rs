@@ -0,0 +1,186 @@
+use solana_program::{
+  account_info::{next_account_info, AccountInfo},
+  entrypoint::ProgramResult,
+  hash::hashv,
+  pubkey::Pubkey,
+  program_error::ProgramError,
+  sysvar::{clock::Clock, Sysvar},
+};
+use crate::state::{Commitment, GameMode, GameStatus};
+use crate::error::BiddingError;
+use crate::layout::load_account;
+use crate::zero_copy::{load_view_mut, GameStateView};
+use crate::utils::{
+  game_pda_seeds,
+  commitment_pda_seeds,
+  check_vault,
+  next_token_accounts,
+  pay_from_vault,
+  close_pda,
+  min_next_bid,
+  TokenAccounts
+};
+
+pub fn reveal_bid(
+  program_id: &Pubkey,
+  accounts: &[AccountInfo],
+  game_id: u64,
+  amount: u64,
+  salt: [u8; 32],
+) -> ProgramResult {
+  let accounts_iter = &mut accounts.iter();
+  let game_account = next_account_info(accounts_iter)?;
+  let bidder_account = next_account_info(accounts_iter)?;
+  let commitment_account = next_account_info(accounts_iter)?;
+  let vault_account = next_account_info(accounts_iter)?;
+
+  if !bidder_account.is_signer {
+    return Err(ProgramError::MissingRequiredSignature);
+  }
+
+  let (game_pda, _) = game_pda_seeds(game_id, program_id);
+  if *game_account.key != game_pda || game_account.owner != program_id {
+    return Err(BiddingError::InvalidGameAccount.into());
+  }
+
+  let mut game = load_view_mut::<GameStateView>(program_id, game_account)?;
+
+  if game.status()? != GameStatus::Active {
+    return Err(BiddingError::GameEnded.into());
+  }
+
+  if game.mode()? != GameMode::Sealed {
+    return Err(BiddingError::InvalidGameMode.into());
+  }
+
+  let current_time = Clock::get()?.unix_timestamp as u64;
+
+  if current_time <= game.deadline.get() || current_time > game.reveal_deadline.get() {
+    return Err(BiddingError::RevealWindowClosed.into());
+  }
+
+  check_vault(program_id, game_id, &game.mint, vault_account)?;
+  let token_accounts = next_token_accounts(&game.mint, accounts_iter)?;
+
+  let commitment = load_commitment(program_id, game_id, commitment_account, bidder_account.key)?;
+
+  if hashv(&[&amount.to_le_bytes(), &salt]).to_bytes() != commitment.hash {
+    return Err(BiddingError::CommitmentMismatch.into());
+  }
+
+  if amount > commitment.deposit {
+    return Err(BiddingError::InsufficientDeposit.into());
+  }
+
+  let leader = game.revealed_leader()?;
+  if leader == Some(*bidder_account.key) {
+    return Err(BiddingError::BidAlreadyRevealed.into());
+  }
+
+  let escrowed_deposits = game.escrowed_deposits.get();
+  let opening_minimum = min_next_bid(game.initial_bid_amount.get(), game.rules.bid_multiplier_bps.get());
+
+  if amount < opening_minimum || amount <= game.highest_bid.get() {
+    game.escrowed_deposits.set(escrowed_deposits - commitment.deposit);
+    drop(game);
+
+    return refund_deposit(
+      program_id,
+      game_id,
+      game_account,
+      vault_account,
+      commitment_account,
+      bidder_account,
+      token_accounts,
+      commitment.deposit,
+    );
+  }
+
+  game.highest_bid.set(amount);
+  game.last_bid_time.set(current_time);
+  game.last_bidder = *bidder_account.key;
+
+  let previous_leader = match leader {
+    Some(previous_leader) => previous_leader,
+    None => return Ok(()),
+  };
+
+  let previous_commitment_account = next_account_info(accounts_iter)?;
+  let previous_bidder_account = next_account_info(accounts_iter)?;
+
+  if *previous_bidder_account.key != previous_leader {
+    return Err(BiddingError::InvalidRecipientAccount.into());
+  }
+
+  let previous_commitment = load_commitment(
+    program_id,
+    game_id,
+    previous_commitment_account,
+    &previous_leader,
+  )?;
+
+  game.escrowed_deposits.set(escrowed_deposits - previous_commitment.deposit);
+  drop(game);
+
+  let previous_token_accounts = match token_accounts {
+    Some(token_accounts) => Some(token_accounts.with_token_account(next_account_info(accounts_iter)?)),
+    None => None,
+  };
+
+  refund_deposit(
+    program_id,
+    game_id,
+    game_account,
+    vault_account,
+    previous_commitment_account,
+    previous_bidder_account,
+    previous_token_accounts,
+    previous_commitment.deposit,
+  )
+}
+
+pub fn load_commitment(
+  program_id: &Pubkey,
+  game_id: u64,
+  commitment_account: &AccountInfo,
+  bidder: &Pubkey,
+) -> Result<Commitment, ProgramError> {
+  let (commitment_pda, _) = commitment_pda_seeds(game_id, bidder, program_id);
+  if *commitment_account.key != commitment_pda || commitment_account.owner != program_id {
+    return Err(BiddingError::InvalidCommitmentAccount.into());
+  }
+
+  load_account::<Commitment>(program_id, commitment_account)
+    .map_err(|_| BiddingError::InvalidCommitmentAccount.into())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn refund_deposit<'a>(
+  program_id: &Pubkey,
+  game_id: u64,
+  game_account: &AccountInfo<'a>,
+  vault_account: &AccountInfo<'a>,
+  commitment_account: &AccountInfo<'a>,
+  bidder_account: &AccountInfo<'a>,
+  token_accounts: Option<TokenAccounts<'_, 'a>>,
+  refund: u64,
+) -> ProgramResult {
+  close_pda(commitment_account, bidder_account)?;
+
+  if refund == 0 {
+    return Ok(());
+  }
+
+  let destination = token_accounts.map_or(bidder_account, |token_accounts| token_accounts.token_account);
+
+  pay_from_vault(
+    program_id,
+    game_id,
+    game_account,
+    vault_account,
+    destination,
+    bidder_account.key,
+    token_accounts,
+    refund,
+  )
+}
@@ -0,0 +1,28 @@
+use solana_program::{
+  account_info::{next_account_info, AccountInfo},
+  entrypoint::ProgramResult,
+  pubkey::Pubkey,
+  program_error::ProgramError,
+};
+use crate::error::BiddingError;
+use crate::utils::{session_pda_seeds, close_pda};
+
+pub fn revoke_session(
+  program_id: &Pubkey,
+  accounts: &[AccountInfo],
+) -> ProgramResult {
+  let accounts_iter = &mut accounts.iter();
+  let owner_account = next_account_info(accounts_iter)?;
+  let session_account = next_account_info(accounts_iter)?;
+
+  if !owner_account.is_signer {
+    return Err(ProgramError::MissingRequiredSignature);
+  }
+
+  let (session_pda, _) = session_pda_seeds(owner_account.key, program_id);
+  if *session_account.key != session_pda || session_account.owner != program_id {
+    return Err(BiddingError::InvalidSessionAccount.into());
+  }
+
+  close_pda(session_account, owner_account)
+}
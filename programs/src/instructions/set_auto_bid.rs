@@ -0,0 +1,133 @@
+use solana_program::{
+  account_info::{next_account_info, AccountInfo},
+  entrypoint::ProgramResult,
+  pubkey::Pubkey,
+  program_error::ProgramError,
+  sysvar::{clock::Clock, Sysvar},
+};
+use crate::state::{AutoBid, GameMode, GameState, GameStatus, AUTO_BID_ACCOUNT_SIZE};
+use crate::error::BiddingError;
+use crate::layout::{load_account, store_account};
+use crate::utils::{
+  game_pda_seeds,
+  auto_bid_pda_seeds,
+  check_vault,
+  next_token_accounts,
+  deposit_to_vault,
+  pay_from_vault,
+  create_pda_account
+};
+
+pub fn set_auto_bid(
+  program_id: &Pubkey,
+  accounts: &[AccountInfo],
+  game_id: u64,
+  max_budget: u64,
+) -> ProgramResult {
+  let accounts_iter = &mut accounts.iter();
+  let system_program = next_account_info(accounts_iter)?;
+  let game_account = next_account_info(accounts_iter)?;
+  let player_account = next_account_info(accounts_iter)?;
+  let auto_bid_account = next_account_info(accounts_iter)?;
+  let vault_account = next_account_info(accounts_iter)?;
+
+  if !player_account.is_signer {
+    return Err(ProgramError::MissingRequiredSignature);
+  }
+
+  let (game_pda, _) = game_pda_seeds(game_id, program_id);
+  if *game_account.key != game_pda || game_account.owner != program_id {
+    return Err(BiddingError::InvalidGameAccount.into());
+  }
+
+  let mut game_state = load_account::<GameState>(program_id, game_account)?;
+
+  if game_state.status != GameStatus::Active {
+    return Err(BiddingError::GameEnded.into());
+  }
+
+  if game_state.mode != GameMode::Open {
+    return Err(BiddingError::InvalidGameMode.into());
+  }
+
+  let current_time = Clock::get()?.unix_timestamp as u64;
+
+  if current_time > game_state.deadline {
+    return Err(BiddingError::BiddingClosed.into());
+  }
+
+  check_vault(program_id, game_id, &game_state.mint, vault_account)?;
+  let token_accounts = next_token_accounts(&game_state.mint, accounts_iter)?;
+  let refund_destination = token_accounts.map_or(player_account, |token_accounts| token_accounts.token_account);
+
+  let (auto_bid_pda, auto_bid_bump) = auto_bid_pda_seeds(game_id, player_account.key, program_id);
+  if *auto_bid_account.key != auto_bid_pda {
+    return Err(BiddingError::InvalidAutoBidAccount.into());
+  }
+
+  let mut auto_bid = if auto_bid_account.data_len() == 0 {
+    create_pda_account(
+      program_id,
+      player_account,
+      auto_bid_account,
+      system_program,
+      AUTO_BID_ACCOUNT_SIZE,
+      &[
+        b"autobid",
+        &game_id.to_le_bytes(),
+        player_account.key.as_ref(),
+        &[auto_bid_bump]
+      ],
+    )?;
+
+    AutoBid {
+      game_id,
+      player: *player_account.key,
+      budget: 0,
+    }
+  } else {
+    if auto_bid_account.owner != program_id {
+      return Err(BiddingError::InvalidAutoBidAccount.into());
+    }
+
+    load_account::<AutoBid>(program_id, auto_bid_account)
+      .map_err(|_| BiddingError::InvalidAutoBidAccount)?
+  };
+
+  let mut refund = 0;
+
+  if max_budget > auto_bid.budget {
+    let received_amount = deposit_to_vault(
+      player_account,
+      vault_account,
+      system_program,
+      token_accounts,
+      max_budget - auto_bid.budget,
+    )?;
+
+    auto_bid.budget += received_amount;
+    game_state.auto_bid_escrow += received_amount;
+  } else {
+    refund = auto_bid.budget - max_budget;
+    auto_bid.budget = max_budget;
+    game_state.auto_bid_escrow -= refund;
+  }
+
+  store_account(auto_bid_account, &auto_bid)?;
+  store_account(game_account, &game_state)?;
+
+  if refund > 0 {
+    pay_from_vault(
+      program_id,
+      game_id,
+      game_account,
+      vault_account,
+      refund_destination,
+      player_account.key,
+      token_accounts,
+      refund,
+    )?;
+  }
+
+  Ok(())
+}
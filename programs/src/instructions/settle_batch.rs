@@ -0,0 +1,113 @@
+use solana_program::{
+  account_info::{next_account_info, AccountInfo},
+  entrypoint::ProgramResult,
+  pubkey::Pubkey,
+};
+use crate::state::{GameState, GameStatus, Settlement};
+use crate::error::BiddingError;
+use crate::layout::{load_account, store_account};
+use crate::ledger::find_bids;
+use crate::zero_copy::{load_view_mut, BidView, PlayerStateView};
+use crate::instructions::end_game::pay_winner;
+use crate::utils::{
+  game_pda_seeds,
+  player_pda_seeds,
+  settlement_pda_seeds,
+  check_vault,
+  next_token_accounts,
+  find_account,
+  close_pda
+};
+
+pub fn settle_batch<'a, 'b: 'a>(
+  program_id: &Pubkey,
+  accounts: &'a [AccountInfo<'b>],
+  start: u64,
+  count: u64,
+) -> ProgramResult {
+  let accounts_iter = &mut accounts.iter();
+  let game_account = next_account_info(accounts_iter)?;
+  let settlement_account = next_account_info(accounts_iter)?;
+  let vault_account = next_account_info(accounts_iter)?;
+
+  if game_account.owner != program_id {
+    return Err(BiddingError::InvalidGameAccount.into());
+  }
+
+  let mut game_state = load_account::<GameState>(program_id, game_account)?;
+  let game_id = game_state.game_id;
+
+  let (game_pda, _) = game_pda_seeds(game_id, program_id);
+  if *game_account.key != game_pda {
+    return Err(BiddingError::InvalidGameAccount.into());
+  }
+
+  check_vault(program_id, game_id, &game_state.mint, vault_account)?;
+  let token_accounts = next_token_accounts(&game_state.mint, accounts_iter)?;
+
+  let (settlement_pda, _) = settlement_pda_seeds(game_id, program_id);
+  if *settlement_account.key != settlement_pda || settlement_account.owner != program_id {
+    return Err(BiddingError::InvalidSettlementAccount.into());
+  }
+
+  let mut settlement = load_account::<Settlement>(program_id, settlement_account)
+    .map_err(|_| BiddingError::InvalidSettlementAccount)?;
+
+  if start > settlement.next_index {
+    return Err(BiddingError::SettlementOutOfOrder.into());
+  }
+
+  let end = start.saturating_add(count).min(settlement.eligible_bids);
+
+  if end > settlement.next_index {
+    let bids = find_bids(program_id, game_id, settlement.next_index + 1, end, accounts)?;
+
+    for (offset, bid) in bids.iter().enumerate() {
+      let index = settlement.next_index + offset as u64;
+      record_royalty(program_id, game_id, index, bid, &mut settlement, accounts)?;
+    }
+
+    settlement.next_index = end;
+    store_account(settlement_account, &settlement)?;
+  }
+
+  if settlement.next_index == settlement.eligible_bids && game_state.status == GameStatus::Settling {
+    let amount = game_state.prize_pool
+      .saturating_sub(settlement.platform_fee.saturating_add(settlement.total_entitled));
+    pay_winner(program_id, game_account, vault_account, token_accounts, accounts, &mut game_state, amount)?;
+
+    let payer_account = find_account(&settlement.payer, accounts)
+      .map_err(|_| BiddingError::InvalidPayerAccount)?;
+    close_pda(settlement_account, payer_account)?;
+  }
+
+  Ok(())
+}
+
+fn record_royalty<'a, 'b: 'a>(
+  program_id: &Pubkey,
+  game_id: u64,
+  index: u64,
+  bid: &BidView,
+  settlement: &mut Settlement,
+  accounts: &'a [AccountInfo<'b>],
+) -> ProgramResult {
+  let weight = settlement.eligible_bids - index;
+  let amount = bid.amount.get();
+  let share = weight as u128 * amount as u128;
+  let royalty_share = (share * settlement.royalty_amount as u128
+    / (settlement.total_weight as u128 * settlement.total_bid_amount as u128)) as u64;
+
+  let (player_pda, _player_bump) = player_pda_seeds(game_id, &bid.bidder, index + 1, program_id);
+  let player_account = find_account(&player_pda, accounts)
+    .map_err(|_| BiddingError::PlayerAccountNotFound)?;
+  let mut player = load_view_mut::<PlayerStateView>(program_id, player_account)?;
+
+  let royalty_earned = player.royalty_earned.get() + royalty_share;
+  player.safe.set(true);
+  player.royalty_earned.set(royalty_earned);
+
+  settlement.total_entitled += amount + royalty_share;
+
+  Ok(())
+}
@@ -0,0 +1,47 @@
+use solana_program::{
+  account_info::{next_account_info, AccountInfo},
+  entrypoint::ProgramResult,
+  pubkey::Pubkey,
+  program_error::ProgramError,
+};
+use crate::instructions::{ConfigParams, validate_config_params};
+use crate::error::BiddingError;
+use crate::layout::store_account;
+use crate::utils::load_config;
+
+pub fn update_config(
+  program_id: &Pubkey,
+  accounts: &[AccountInfo],
+  params: ConfigParams,
+  new_authority: Option<Pubkey>,
+) -> ProgramResult {
+  let accounts_iter = &mut accounts.iter();
+  let config_account = next_account_info(accounts_iter)?;
+  let authority_account = next_account_info(accounts_iter)?;
+
+  if !authority_account.is_signer {
+    return Err(ProgramError::MissingRequiredSignature);
+  }
+
+  let mut config = load_config(program_id, config_account)?;
+
+  if config.authority != *authority_account.key {
+    return Err(BiddingError::InvalidAuthority.into());
+  }
+
+  validate_config_params(&params)?;
+
+  config.platform_fee_percentage = params.platform_fee_percentage;
+  config.min_initial_bid = params.min_initial_bid;
+  config.default_rules = params.default_rules;
+  config.min_rules = params.min_rules;
+  config.max_rules = params.max_rules;
+
+  if let Some(new_authority) = new_authority {
+    config.authority = new_authority;
+  }
+
+  store_account(config_account, &config)?;
+
+  Ok(())
+}
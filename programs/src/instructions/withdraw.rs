@@ -0,0 +1,30 @@
+use solana_program::{
+  account_info::{next_account_info, AccountInfo},
+  entrypoint::ProgramResult,
+  pubkey::Pubkey,
+  program_error::ProgramError,
+};
+use crate::error::BiddingError;
+use crate::utils::{check_balance_account, available_lamports, transfer_from_pda};
+
+pub fn withdraw(
+  program_id: &Pubkey,
+  accounts: &[AccountInfo],
+  amount: u64,
+) -> ProgramResult {
+  let accounts_iter = &mut accounts.iter();
+  let owner_account = next_account_info(accounts_iter)?;
+  let balance_account = next_account_info(accounts_iter)?;
+
+  if !owner_account.is_signer {
+    return Err(ProgramError::MissingRequiredSignature);
+  }
+
+  check_balance_account(program_id, balance_account, owner_account.key)?;
+
+  if amount > available_lamports(balance_account)? {
+    return Err(BiddingError::InsufficientBalance.into());
+  }
+
+  transfer_from_pda(balance_account, owner_account, amount)
+}
@@ -0,0 +1,91 @@
+use solana_program::{
+  account_info::{next_account_info, AccountInfo},
+  entrypoint::ProgramResult,
+  pubkey::Pubkey,
+  program::invoke_signed,
+  program_error::ProgramError,
+};
+use crate::error::BiddingError;
+use crate::utils::{
+  treasury_pda_seeds,
+  load_config,
+  transfer_from_pda,
+  available_lamports,
+  read_token_accounts,
+  check_token_account,
+  mint_decimals,
+  TokenAccounts
+};
+
+pub fn withdraw_fees(
+  program_id: &Pubkey,
+  accounts: &[AccountInfo],
+  amount: u64,
+) -> ProgramResult {
+  let accounts_iter = &mut accounts.iter();
+  let config_account = next_account_info(accounts_iter)?;
+  let authority_account = next_account_info(accounts_iter)?;
+  let treasury_account = next_account_info(accounts_iter)?;
+  let destination_account = next_account_info(accounts_iter)?;
+
+  if !authority_account.is_signer {
+    return Err(ProgramError::MissingRequiredSignature);
+  }
+
+  let config = load_config(program_id, config_account)?;
+
+  if config.authority != *authority_account.key {
+    return Err(BiddingError::InvalidAuthority.into());
+  }
+
+  let (treasury_pda, _) = treasury_pda_seeds(program_id);
+  if *treasury_account.key != treasury_pda || treasury_account.owner != program_id {
+    return Err(BiddingError::InvalidTreasuryAccount.into());
+  }
+
+  if accounts_iter.len() > 0 {
+    let token_accounts = read_token_accounts(accounts_iter)?;
+    return withdraw_token_fees(program_id, token_accounts, treasury_account, destination_account, amount);
+  }
+
+  if amount > available_lamports(treasury_account)? {
+    return Err(BiddingError::InsufficientFunds.into());
+  }
+
+  transfer_from_pda(treasury_account, destination_account, amount)
+}
+
+fn withdraw_token_fees<'a, 'b>(
+  program_id: &Pubkey,
+  token_accounts: TokenAccounts<'a, 'b>,
+  treasury_account: &'a AccountInfo<'b>,
+  destination_account: &'a AccountInfo<'b>,
+  amount: u64,
+) -> ProgramResult {
+  let TokenAccounts { token_program, mint, token_account: treasury_token_account } = token_accounts;
+
+  check_token_account(treasury_token_account, mint.key, treasury_account.key)?;
+
+  let (_, treasury_bump) = treasury_pda_seeds(program_id);
+
+  invoke_signed(
+    &spl_token_2022::instruction::transfer_checked(
+      token_program.key,
+      treasury_token_account.key,
+      mint.key,
+      destination_account.key,
+      treasury_account.key,
+      &[],
+      amount,
+      mint_decimals(mint)?,
+    )?,
+    &[
+      treasury_token_account.clone(),
+      mint.clone(),
+      destination_account.clone(),
+      treasury_account.clone(),
+      token_program.clone(),
+    ],
+    &[&[b"treasury", &[treasury_bump]]],
+  )
+}
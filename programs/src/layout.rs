@@ -0,0 +1,119 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+  account_info::AccountInfo,
+  entrypoint::ProgramResult,
+  program_error::ProgramError,
+  pubkey::Pubkey,
+};
+use crate::error::BiddingError;
+use crate::state::AccountKind;
+
+pub const HEADER_LEN: usize = 2;
+
+pub trait FixedSize {
+  const SIZE: usize;
+}
+
+impl FixedSize for u8 {
+  const SIZE: usize = 1;
+}
+
+impl FixedSize for bool {
+  const SIZE: usize = 1;
+}
+
+impl FixedSize for u64 {
+  const SIZE: usize = 8;
+}
+
+impl FixedSize for Pubkey {
+  const SIZE: usize = 32;
+}
+
+impl<const N: usize> FixedSize for [u8; N] {
+  const SIZE: usize = N;
+}
+
+pub trait AccountLayout: FixedSize + BorshSerialize + BorshDeserialize {
+  const KIND: AccountKind;
+  const VERSION: u8;
+  const SPACE: usize = HEADER_LEN + Self::SIZE;
+}
+
+macro_rules! fixed_layout {
+  (
+    $(#[$meta:meta])*
+    pub struct $name:ident {
+      $(pub $field:ident: $ty:ty,)*
+    }
+  ) => {
+    $(#[$meta])*
+    pub struct $name {
+      $(pub $field: $ty,)*
+    }
+
+    impl $crate::layout::FixedSize for $name {
+      const SIZE: usize = 0 $(+ <$ty as $crate::layout::FixedSize>::SIZE)*;
+    }
+  };
+}
+
+pub(crate) use fixed_layout;
+
+pub fn check_tag<T: AccountLayout>(
+  program_id: &Pubkey,
+  account: &AccountInfo,
+  data: &[u8],
+) -> ProgramResult {
+  if account.owner != program_id {
+    return Err(BiddingError::InvalidAccountOwner.into());
+  }
+
+  if data.first() != Some(&(T::KIND as u8)) {
+    return Err(BiddingError::InvalidAccountType.into());
+  }
+
+  if data.get(1) != Some(&T::VERSION) {
+    return Err(BiddingError::UnsupportedAccountVersion.into());
+  }
+
+  Ok(())
+}
+
+pub fn check_header<T: AccountLayout>(
+  program_id: &Pubkey,
+  account: &AccountInfo,
+  data: &[u8],
+) -> ProgramResult {
+  check_tag::<T>(program_id, account, data)?;
+
+  if data.len() != T::SPACE {
+    return Err(ProgramError::InvalidAccountData);
+  }
+
+  Ok(())
+}
+
+pub fn load_account<T: AccountLayout>(
+  program_id: &Pubkey,
+  account: &AccountInfo,
+) -> Result<T, ProgramError> {
+  let data = account.try_borrow_data()?;
+  check_header::<T>(program_id, account, &data)?;
+
+  T::try_from_slice(&data[HEADER_LEN..]).map_err(|_| ProgramError::InvalidAccountData)
+}
+
+pub fn store_account<T: AccountLayout>(account: &AccountInfo, value: &T) -> ProgramResult {
+  let mut data = account.try_borrow_mut_data()?;
+
+  if data.len() != T::SPACE {
+    return Err(ProgramError::InvalidAccountData);
+  }
+
+  data[0] = T::KIND as u8;
+  data[1] = T::VERSION;
+  value.serialize(&mut &mut data[HEADER_LEN..])?;
+
+  Ok(())
+}
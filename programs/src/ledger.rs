@@ -0,0 +1,208 @@
+use std::cell::Ref;
+use solana_program::{
+  account_info::AccountInfo,
+  entrypoint::ProgramResult,
+  program_error::ProgramError,
+  pubkey::Pubkey,
+  sysvar::{rent::Rent, Sysvar},
+};
+use crate::error::BiddingError;
+use crate::layout::{check_tag, store_account, FixedSize, HEADER_LEN};
+use crate::state::{Ledger, BID_RECORD_SIZE, LEDGER_ACCOUNT_SIZE};
+use crate::utils::{
+  ledger_pda_seeds,
+  create_pda_account,
+  fund_account,
+  find_account,
+  transfer_from_pda,
+  close_pda
+};
+use crate::zero_copy::{BidView, LedgerView};
+
+pub fn create_ledger<'a>(
+  program_id: &Pubkey,
+  payer_account: &AccountInfo<'a>,
+  ledger_account: &AccountInfo<'a>,
+  system_program: &AccountInfo<'a>,
+  game_id: u64,
+) -> ProgramResult {
+  let (ledger_pda, ledger_bump) = ledger_pda_seeds(game_id, program_id);
+  if *ledger_account.key != ledger_pda {
+    return Err(BiddingError::InvalidLedgerAccount.into());
+  }
+
+  create_pda_account(
+    program_id,
+    payer_account,
+    ledger_account,
+    system_program,
+    LEDGER_ACCOUNT_SIZE,
+    &[b"ledger", &game_id.to_le_bytes(), &[ledger_bump]],
+  )?;
+
+  store_account(ledger_account, &Ledger {
+    game_id,
+    len: 0,
+    payer: *payer_account.key,
+  })
+}
+
+fn check_ledger(
+  program_id: &Pubkey,
+  ledger_account: &AccountInfo,
+  data: &[u8],
+  game_id: u64,
+) -> Result<LedgerView, ProgramError> {
+  check_tag::<Ledger>(program_id, ledger_account, data)
+    .map_err(|_| BiddingError::InvalidLedgerAccount)?;
+
+  if data.len() < LEDGER_ACCOUNT_SIZE {
+    return Err(ProgramError::InvalidAccountData);
+  }
+
+  let ledger = *bytemuck::from_bytes::<LedgerView>(&data[HEADER_LEN..LEDGER_ACCOUNT_SIZE]);
+  if ledger.game_id.get() != game_id {
+    return Err(BiddingError::InvalidLedgerAccount.into());
+  }
+
+  let len = ledger.len.get();
+  if data.len() != LEDGER_ACCOUNT_SIZE + len as usize * BID_RECORD_SIZE {
+    return Err(ProgramError::InvalidAccountData);
+  }
+
+  Ok(ledger)
+}
+
+pub fn ledger_len(
+  program_id: &Pubkey,
+  ledger_account: &AccountInfo,
+  game_id: u64,
+) -> Result<u64, ProgramError> {
+  Ok(check_ledger(program_id, ledger_account, &ledger_account.try_borrow_data()?, game_id)?.len.get())
+}
+
+pub fn ledger_growth_rent(ledger_account: &AccountInfo) -> Result<u64, ProgramError> {
+  let rent = Rent::get()?;
+  let lamports = rent.minimum_balance(ledger_account.data_len() + BID_RECORD_SIZE);
+  Ok(lamports.saturating_sub(ledger_account.lamports()))
+}
+
+pub fn append_bid<'a>(
+  program_id: &Pubkey,
+  payer_account: &AccountInfo<'a>,
+  ledger_account: &AccountInfo<'a>,
+  system_program: &AccountInfo<'a>,
+  game_id: u64,
+  bid_number: u64,
+  bid: BidView,
+) -> ProgramResult {
+  let (ledger_pda, _) = ledger_pda_seeds(game_id, program_id);
+  if *ledger_account.key != ledger_pda {
+    return Err(BiddingError::InvalidLedgerAccount.into());
+  }
+
+  let len = check_ledger(program_id, ledger_account, &ledger_account.try_borrow_data()?, game_id)?.len.get();
+  if len + 1 != bid_number {
+    return Err(BiddingError::BidCountMismatch.into());
+  }
+
+  let top_up = ledger_growth_rent(ledger_account)?;
+  if top_up > 0 {
+    fund_account(program_id, payer_account, ledger_account, system_program, top_up)?;
+  }
+
+  ledger_account.realloc(ledger_account.data_len() + BID_RECORD_SIZE, false)?;
+
+  let mut data = ledger_account.try_borrow_mut_data()?;
+  let (ledger, records) = data[HEADER_LEN..].split_at_mut(Ledger::SIZE);
+  bytemuck::from_bytes_mut::<LedgerView>(ledger).len.set(bid_number);
+  *bytemuck::from_bytes_mut::<BidView>(&mut records[len as usize * BID_RECORD_SIZE..]) = bid;
+
+  Ok(())
+}
+
+pub fn load_bids<'a>(
+  program_id: &Pubkey,
+  ledger_account: &'a AccountInfo,
+  game_id: u64,
+  first_bid: u64,
+  last_bid: u64,
+) -> Result<Ref<'a, [BidView]>, ProgramError> {
+  let data = ledger_account.try_borrow_data()?;
+  let len = check_ledger(program_id, ledger_account, &data, game_id)?.len.get();
+
+  if first_bid == 0 || first_bid > last_bid + 1 || last_bid > len {
+    return Err(BiddingError::BidNotRecorded.into());
+  }
+
+  let start = LEDGER_ACCOUNT_SIZE + (first_bid - 1) as usize * BID_RECORD_SIZE;
+  let end = LEDGER_ACCOUNT_SIZE + last_bid as usize * BID_RECORD_SIZE;
+
+  Ok(Ref::map(data, |data| bytemuck::cast_slice(&data[start..end])))
+}
+
+pub fn find_bids<'a, 'b: 'a>(
+  program_id: &Pubkey,
+  game_id: u64,
+  first_bid: u64,
+  last_bid: u64,
+  accounts: &'a [AccountInfo<'b>],
+) -> Result<Ref<'a, [BidView]>, ProgramError> {
+  let (ledger_pda, _) = ledger_pda_seeds(game_id, program_id);
+  let ledger_account = find_account(&ledger_pda, accounts)
+    .map_err(|_| BiddingError::LedgerAccountNotFound)?;
+
+  load_bids(program_id, ledger_account, game_id, first_bid, last_bid)
+}
+
+pub fn release_bid<'a>(
+  program_id: &Pubkey,
+  ledger_account: &AccountInfo<'a>,
+  game_id: u64,
+  payer_account: &AccountInfo<'a>,
+  accounts: &[AccountInfo<'a>],
+) -> ProgramResult {
+  let ledger = check_ledger(program_id, ledger_account, &ledger_account.try_borrow_data()?, game_id)?;
+  let len = ledger.len.get();
+
+  if len == 0 {
+    return Err(BiddingError::BidNotRecorded.into());
+  }
+
+  let rent = Rent::get()?;
+  let record_rent = rent.minimum_balance(BID_RECORD_SIZE) - rent.minimum_balance(0);
+  transfer_from_pda(ledger_account, payer_account, record_rent)?;
+
+  let remaining = len - 1;
+  ledger_account.realloc(LEDGER_ACCOUNT_SIZE + remaining as usize * BID_RECORD_SIZE, false)?;
+  bytemuck::from_bytes_mut::<LedgerView>(&mut ledger_account.try_borrow_mut_data()?[HEADER_LEN..LEDGER_ACCOUNT_SIZE])
+    .len
+    .set(remaining);
+
+  if remaining == 0 {
+    if let Ok(ledger_payer_account) = find_account(&ledger.payer, accounts) {
+      return close_ledger_account(program_id, ledger_account, game_id, ledger_payer_account);
+    }
+  }
+
+  Ok(())
+}
+
+pub fn close_ledger_account<'a>(
+  program_id: &Pubkey,
+  ledger_account: &AccountInfo<'a>,
+  game_id: u64,
+  payer_account: &AccountInfo<'a>,
+) -> ProgramResult {
+  let ledger = check_ledger(program_id, ledger_account, &ledger_account.try_borrow_data()?, game_id)?;
+
+  if ledger.len.get() != 0 {
+    return Err(BiddingError::PlayerAccountsOutstanding.into());
+  }
+
+  if *payer_account.key != ledger.payer {
+    return Err(BiddingError::InvalidPayerAccount.into());
+  }
+
+  close_pda(ledger_account, payer_account)
+}
@@ -6,5 +6,8 @@ pub mod processor;
 pub mod state;
 pub mod error;
 pub mod utils;
+pub mod layout;
+pub mod zero_copy;
+pub mod ledger;
 
 entrypoint!(process_instruction);
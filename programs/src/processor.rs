@@ -5,7 +5,32 @@ use solana_program::{
   pubkey::Pubkey,
 };
 
-use crate::instructions::{BiddingInstruction, create_game, place_bid};
+use crate::instructions::{
+  BiddingInstruction,
+  create_game,
+  place_bid,
+  end_game,
+  settle_batch,
+  claim_royalty,
+  cancel_game,
+  close_accounts,
+  initialize_config,
+  update_config,
+  withdraw_fees,
+  commit_bid,
+  reveal_bid,
+  quote_price,
+  set_auto_bid,
+  crank_auto_bid,
+  cancel_auto_bid,
+  deposit,
+  withdraw,
+  place_bid_from_balance,
+  register_session,
+  revoke_session,
+  migrate_account,
+  close_ledger,
+};
 use crate::error::BiddingError;
 
 pub fn process_instruction(
@@ -17,11 +42,74 @@ pub fn process_instruction(
     .map_err(|_| BiddingError::InvalidInstruction)?;
 
   match instruction {
-    BiddingInstruction::CreateGame { game_id, initial_bid_amount } => {
-      create_game(program_id, accounts, game_id, initial_bid_amount)
+    BiddingInstruction::CreateGame { game_id, initial_bid_amount, rules, mode, price_curve, ends_at } => {
+      create_game(program_id, accounts, game_id, initial_bid_amount, rules, mode, price_curve, ends_at)
     },
     BiddingInstruction::PlaceBid { bid_amount , bid_count} => {
       place_bid(program_id, accounts, bid_amount, bid_count)
     },
+    BiddingInstruction::EndGame { game_id } => {
+      end_game(program_id, accounts, game_id)
+    },
+    BiddingInstruction::SettleBatch { start, count } => {
+      settle_batch(program_id, accounts, start, count)
+    },
+    BiddingInstruction::ClaimRoyalty { game_id, bid_count } => {
+      claim_royalty(program_id, accounts, game_id, bid_count)
+    },
+    BiddingInstruction::CancelGame { game_id } => {
+      cancel_game(program_id, accounts, game_id)
+    },
+    BiddingInstruction::CloseAccounts { game_id } => {
+      close_accounts(program_id, accounts, game_id)
+    },
+    BiddingInstruction::InitializeConfig { params } => {
+      initialize_config(program_id, accounts, params)
+    },
+    BiddingInstruction::UpdateConfig { params, new_authority } => {
+      update_config(program_id, accounts, params, new_authority)
+    },
+    BiddingInstruction::WithdrawFees { amount } => {
+      withdraw_fees(program_id, accounts, amount)
+    },
+    BiddingInstruction::CommitBid { game_id, commitment, deposit } => {
+      commit_bid(program_id, accounts, game_id, commitment, deposit)
+    },
+    BiddingInstruction::RevealBid { game_id, amount, salt } => {
+      reveal_bid(program_id, accounts, game_id, amount, salt)
+    },
+    BiddingInstruction::QuotePrice { game_id } => {
+      quote_price(program_id, accounts, game_id)
+    },
+    BiddingInstruction::SetAutoBid { game_id, max_budget } => {
+      set_auto_bid(program_id, accounts, game_id, max_budget)
+    },
+    BiddingInstruction::CrankAutoBid { game_id, bid_count } => {
+      crank_auto_bid(program_id, accounts, game_id, bid_count)
+    },
+    BiddingInstruction::CancelAutoBid { game_id } => {
+      cancel_auto_bid(program_id, accounts, game_id)
+    },
+    BiddingInstruction::Deposit { amount } => {
+      deposit(program_id, accounts, amount)
+    },
+    BiddingInstruction::Withdraw { amount } => {
+      withdraw(program_id, accounts, amount)
+    },
+    BiddingInstruction::PlaceBidFromBalance { game_id, bid_amount, bid_count } => {
+      place_bid_from_balance(program_id, accounts, game_id, bid_amount, bid_count)
+    },
+    BiddingInstruction::RegisterSession { session_key, spending_cap, expires_at } => {
+      register_session(program_id, accounts, session_key, spending_cap, expires_at)
+    },
+    BiddingInstruction::RevokeSession => {
+      revoke_session(program_id, accounts)
+    },
+    BiddingInstruction::MigrateAccount => {
+      migrate_account(program_id, accounts)
+    },
+    BiddingInstruction::CloseLedger { game_id } => {
+      close_ledger(program_id, accounts, game_id)
+    },
   }
 }
\ No newline at end of file
@@ -1,34 +1,421 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
+use crate::layout::{fixed_layout, AccountLayout, FixedSize};
 
-#[derive(BorshSerialize, BorshDeserialize, Debug)]
-pub struct GameState {
-  pub game_id: u64,
-  pub initial_bid_amount: u64,
-  pub highest_bid: u64,
-  pub last_bid_time: u64,
-  pub total_bids: u64,
-  pub last_bidder: Pubkey,
-  pub prize_pool: u64,
-  pub platform_fee_percentage: u64,
-  pub game_ended: bool,
-}
-
-#[derive(BorshSerialize, BorshDeserialize, Debug)]
-pub struct PlayerState {
-  pub total_bid_amount: u64,
-  pub safe: bool,
-  pub royalty_earned: u64,
-  pub bid_count: u64,
-}
-
-#[derive(BorshSerialize, BorshDeserialize, Debug)]
-pub struct Bid {
-  pub bidder: Pubkey,
-  pub amount: u64,
-  pub timestamp: u64,
-}
-
-pub const GAME_ACCOUNT_SIZE: usize = 96;
-pub const PLAYER_ACCOUNT_SIZE: usize = 32;
-pub const BID_ACCOUNT_SIZE: usize = 48;
\ No newline at end of file
+fixed_layout! {
+  #[derive(BorshSerialize, BorshDeserialize, Debug)]
+  pub struct GameState {
+    pub game_id: u64,
+    pub initial_bid_amount: u64,
+    pub highest_bid: u64,
+    pub last_bid_time: u64,
+    pub total_bids: u64,
+    pub last_bidder: Pubkey,
+    pub prize_pool: u64,
+    pub platform_fee_percentage: u64,
+    pub rules: GameRules,
+    pub deadline: u64,
+    pub snipe_extension_used: u64,
+    pub mint: Pubkey,
+    pub mode: GameMode,
+    pub reveal_deadline: u64,
+    pub escrowed_deposits: u64,
+    pub price_curve: PriceCurve,
+    pub started_at: u64,
+    pub auto_bid_escrow: u64,
+    pub ends_at: u64,
+    pub status: GameStatus,
+  }
+}
+
+impl GameState {
+  pub fn revealed_leader(&self) -> Option<Pubkey> {
+    let pending = self.mode == GameMode::Sealed
+      && self.total_bids == 1
+      && self.highest_bid > self.initial_bid_amount;
+
+    pending.then_some(self.last_bidder)
+  }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameStatus {
+  Active,
+  Settling,
+  Ended,
+}
+
+impl FixedSize for GameStatus {
+  const SIZE: usize = 1;
+}
+
+fixed_layout! {
+  #[derive(BorshSerialize, BorshDeserialize, Debug)]
+  pub struct GameStateV1 {
+    pub game_id: u64,
+    pub initial_bid_amount: u64,
+    pub highest_bid: u64,
+    pub last_bid_time: u64,
+    pub total_bids: u64,
+    pub last_bidder: Pubkey,
+    pub prize_pool: u64,
+    pub platform_fee_percentage: u64,
+    pub game_ended: bool,
+  }
+}
+
+impl GameStateV1 {
+  pub fn upgrade(self) -> GameState {
+    let rules = GameRules {
+      bid_timeout: 600,
+      bid_multiplier_bps: 2 * BPS_DENOMINATOR,
+      royalty_window: 5,
+      snipe_window: 0,
+      snipe_extension: 0,
+      max_snipe_extension: 0,
+      reveal_window: 0,
+      max_bids_per_wallet: u64::MAX,
+      bid_cooldown: 0,
+      no_self_outbid: false,
+    };
+    let deadline = self.last_bid_time.saturating_add(rules.bid_timeout);
+
+    GameState {
+      game_id: self.game_id,
+      initial_bid_amount: self.initial_bid_amount,
+      highest_bid: self.highest_bid,
+      last_bid_time: self.last_bid_time,
+      total_bids: self.total_bids,
+      last_bidder: self.last_bidder,
+      prize_pool: self.prize_pool,
+      platform_fee_percentage: self.platform_fee_percentage,
+      rules,
+      deadline,
+      snipe_extension_used: 0,
+      mint: Pubkey::default(),
+      mode: GameMode::Open,
+      reveal_deadline: deadline,
+      escrowed_deposits: 0,
+      price_curve: PriceCurve::default(),
+      started_at: self.last_bid_time,
+      auto_bid_escrow: 0,
+      ends_at: u64::MAX,
+      status: if self.game_ended { GameStatus::Ended } else { GameStatus::Active },
+    }
+  }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameMode {
+  Open,
+  Sealed,
+  Dutch,
+}
+
+impl FixedSize for GameMode {
+  const SIZE: usize = 1;
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CurveKind {
+  #[default]
+  Linear,
+  Stepwise,
+}
+
+impl FixedSize for CurveKind {
+  const SIZE: usize = 1;
+}
+
+fixed_layout! {
+  #[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, Default)]
+  pub struct PriceCurve {
+    pub kind: CurveKind,
+    pub start_price: u64,
+    pub floor_price: u64,
+    pub step_interval: u64,
+  }
+}
+
+fixed_layout! {
+  #[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy)]
+  pub struct GameRules {
+    pub bid_timeout: u64,
+    pub bid_multiplier_bps: u64,
+    pub royalty_window: u64,
+    pub snipe_window: u64,
+    pub snipe_extension: u64,
+    pub max_snipe_extension: u64,
+    pub reveal_window: u64,
+    pub max_bids_per_wallet: u64,
+    pub bid_cooldown: u64,
+    pub no_self_outbid: bool,
+  }
+}
+
+fixed_layout! {
+  #[derive(BorshSerialize, BorshDeserialize, Debug)]
+  pub struct PlayerState {
+    pub total_bid_amount: u64,
+    pub safe: bool,
+    pub royalty_earned: u64,
+    pub bid_count: u64,
+    pub claimed: bool,
+    pub bidder: Pubkey,
+    pub payer: Pubkey,
+  }
+}
+
+fixed_layout! {
+  #[derive(BorshSerialize, BorshDeserialize, Debug)]
+  pub struct PlayerStateV1 {
+    pub total_bid_amount: u64,
+    pub safe: bool,
+    pub royalty_earned: u64,
+    pub bid_count: u64,
+  }
+}
+
+impl PlayerStateV1 {
+  pub fn upgrade(self, bidder: Pubkey) -> PlayerState {
+    PlayerState {
+      total_bid_amount: self.total_bid_amount,
+      safe: self.safe,
+      royalty_earned: self.royalty_earned,
+      bid_count: self.bid_count,
+      claimed: self.safe,
+      bidder,
+      payer: bidder,
+    }
+  }
+}
+
+fixed_layout! {
+  #[derive(BorshSerialize, BorshDeserialize, Debug)]
+  pub struct PlayerStats {
+    pub game_id: u64,
+    pub player: Pubkey,
+    pub bid_count: u64,
+    pub last_bid_time: u64,
+    pub payer: Pubkey,
+  }
+}
+
+fixed_layout! {
+  #[derive(BorshSerialize, BorshDeserialize, Debug)]
+  pub struct PlayerStatsV1 {
+    pub game_id: u64,
+    pub player: Pubkey,
+    pub bid_count: u64,
+    pub last_bid_time: u64,
+  }
+}
+
+impl PlayerStatsV1 {
+  pub fn upgrade(self, payer: Pubkey) -> PlayerStats {
+    PlayerStats {
+      game_id: self.game_id,
+      player: self.player,
+      bid_count: self.bid_count,
+      last_bid_time: self.last_bid_time,
+      payer,
+    }
+  }
+}
+
+fixed_layout! {
+  #[derive(BorshSerialize, BorshDeserialize, Debug)]
+  pub struct Bid {
+    pub bidder: Pubkey,
+    pub amount: u64,
+    pub timestamp: u64,
+    pub payer: Pubkey,
+  }
+}
+
+fixed_layout! {
+  #[derive(BorshSerialize, BorshDeserialize, Debug)]
+  pub struct BidV1 {
+    pub bidder: Pubkey,
+    pub amount: u64,
+    pub timestamp: u64,
+  }
+}
+
+fixed_layout! {
+  #[derive(BorshSerialize, BorshDeserialize, Debug)]
+  pub struct Ledger {
+    pub game_id: u64,
+    pub len: u64,
+    pub payer: Pubkey,
+  }
+}
+
+fixed_layout! {
+  #[derive(BorshSerialize, BorshDeserialize, Debug)]
+  pub struct Commitment {
+    pub game_id: u64,
+    pub bidder: Pubkey,
+    pub hash: [u8; 32],
+    pub deposit: u64,
+  }
+}
+
+fixed_layout! {
+  #[derive(BorshSerialize, BorshDeserialize, Debug)]
+  pub struct AutoBid {
+    pub game_id: u64,
+    pub player: Pubkey,
+    pub budget: u64,
+  }
+}
+
+fixed_layout! {
+  #[derive(BorshSerialize, BorshDeserialize, Debug)]
+  pub struct Balance {
+    pub owner: Pubkey,
+  }
+}
+
+fixed_layout! {
+  #[derive(BorshSerialize, BorshDeserialize, Debug)]
+  pub struct Session {
+    pub owner: Pubkey,
+    pub session_key: Pubkey,
+    pub spending_cap: u64,
+    pub spent: u64,
+    pub expires_at: u64,
+  }
+}
+
+fixed_layout! {
+  #[derive(BorshSerialize, BorshDeserialize, Debug)]
+  pub struct Settlement {
+    pub game_id: u64,
+    pub eligible_bids: u64,
+    pub next_index: u64,
+    pub royalty_amount: u64,
+    pub total_weight: u64,
+    pub total_bid_amount: u64,
+    pub platform_fee: u64,
+    pub total_entitled: u64,
+    pub payer: Pubkey,
+  }
+}
+
+fixed_layout! {
+  #[derive(BorshSerialize, BorshDeserialize, Debug)]
+  pub struct PlatformConfig {
+    pub authority: Pubkey,
+    pub platform_fee_percentage: u64,
+    pub min_initial_bid: u64,
+    pub default_rules: GameRules,
+    pub min_rules: GameRules,
+    pub max_rules: GameRules,
+  }
+}
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountKind {
+  Game = 1,
+  Player = 2,
+  Stats = 3,
+  Settlement = 5,
+  Commitment = 6,
+  AutoBid = 7,
+  Balance = 8,
+  Session = 9,
+  Config = 10,
+  Ledger = 11,
+}
+
+impl AccountLayout for GameState {
+  const KIND: AccountKind = AccountKind::Game;
+  const VERSION: u8 = 2;
+}
+
+impl AccountLayout for PlayerState {
+  const KIND: AccountKind = AccountKind::Player;
+  const VERSION: u8 = 1;
+}
+
+impl AccountLayout for PlayerStats {
+  const KIND: AccountKind = AccountKind::Stats;
+  const VERSION: u8 = 2;
+}
+
+impl AccountLayout for PlayerStatsV1 {
+  const KIND: AccountKind = AccountKind::Stats;
+  const VERSION: u8 = 1;
+}
+
+impl AccountLayout for Ledger {
+  const KIND: AccountKind = AccountKind::Ledger;
+  const VERSION: u8 = 1;
+}
+
+impl AccountLayout for Settlement {
+  const KIND: AccountKind = AccountKind::Settlement;
+  const VERSION: u8 = 1;
+}
+
+impl AccountLayout for Commitment {
+  const KIND: AccountKind = AccountKind::Commitment;
+  const VERSION: u8 = 1;
+}
+
+impl AccountLayout for AutoBid {
+  const KIND: AccountKind = AccountKind::AutoBid;
+  const VERSION: u8 = 1;
+}
+
+impl AccountLayout for Balance {
+  const KIND: AccountKind = AccountKind::Balance;
+  const VERSION: u8 = 1;
+}
+
+impl AccountLayout for Session {
+  const KIND: AccountKind = AccountKind::Session;
+  const VERSION: u8 = 1;
+}
+
+impl AccountLayout for PlatformConfig {
+  const KIND: AccountKind = AccountKind::Config;
+  const VERSION: u8 = 1;
+}
+
+pub const GAME_ACCOUNT_SIZE: usize = 278;
+pub const GAME_V1_ACCOUNT_SIZE: usize = 96;
+pub const PLAYER_ACCOUNT_SIZE: usize = 92;
+pub const PLAYER_V1_ACCOUNT_SIZE: usize = 32;
+pub const STATS_ACCOUNT_SIZE: usize = 90;
+pub const STATS_V1_ACCOUNT_SIZE: usize = 58;
+pub const BID_V1_ACCOUNT_SIZE: usize = 48;
+pub const LEDGER_ACCOUNT_SIZE: usize = 50;
+pub const BID_RECORD_SIZE: usize = 80;
+pub const SETTLEMENT_ACCOUNT_SIZE: usize = 98;
+pub const COMMITMENT_ACCOUNT_SIZE: usize = 82;
+pub const AUTO_BID_ACCOUNT_SIZE: usize = 50;
+pub const BALANCE_ACCOUNT_SIZE: usize = 34;
+pub const SESSION_ACCOUNT_SIZE: usize = 90;
+pub const CONFIG_ACCOUNT_SIZE: usize = 269;
+
+const _: () = assert!(<GameState as AccountLayout>::SPACE == GAME_ACCOUNT_SIZE);
+const _: () = assert!(<GameStateV1 as FixedSize>::SIZE <= GAME_V1_ACCOUNT_SIZE);
+const _: () = assert!(<PlayerState as AccountLayout>::SPACE == PLAYER_ACCOUNT_SIZE);
+const _: () = assert!(<PlayerStateV1 as FixedSize>::SIZE <= PLAYER_V1_ACCOUNT_SIZE);
+const _: () = assert!(<PlayerStats as AccountLayout>::SPACE == STATS_ACCOUNT_SIZE);
+const _: () = assert!(<PlayerStatsV1 as AccountLayout>::SPACE == STATS_V1_ACCOUNT_SIZE);
+const _: () = assert!(<BidV1 as FixedSize>::SIZE <= BID_V1_ACCOUNT_SIZE);
+const _: () = assert!(<Ledger as AccountLayout>::SPACE == LEDGER_ACCOUNT_SIZE);
+const _: () = assert!(<Bid as FixedSize>::SIZE == BID_RECORD_SIZE);
+const _: () = assert!(<Settlement as AccountLayout>::SPACE == SETTLEMENT_ACCOUNT_SIZE);
+const _: () = assert!(<Commitment as AccountLayout>::SPACE == COMMITMENT_ACCOUNT_SIZE);
+const _: () = assert!(<AutoBid as AccountLayout>::SPACE == AUTO_BID_ACCOUNT_SIZE);
+const _: () = assert!(<Balance as AccountLayout>::SPACE == BALANCE_ACCOUNT_SIZE);
+const _: () = assert!(<Session as AccountLayout>::SPACE == SESSION_ACCOUNT_SIZE);
+const _: () = assert!(<PlatformConfig as AccountLayout>::SPACE == CONFIG_ACCOUNT_SIZE);
+
+pub const BPS_DENOMINATOR: u64 = 10_000;
+pub const MAX_SESSION_DURATION: u64 = 86_400;
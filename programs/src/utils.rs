@@ -1,19 +1,45 @@
-use borsh::BorshDeserialize;
 use solana_program::{
-  account_info::AccountInfo,
+  account_info::{next_account_info, AccountInfo},
   entrypoint::ProgramResult,
+  program::{invoke, invoke_signed},
   program_error::ProgramError,
   pubkey::Pubkey,
+  system_instruction,
+  sysvar::{rent::Rent, Sysvar},
 };
-use std::collections::HashMap;
+use spl_token_2022::{
+  extension::{
+    transfer_fee::{instruction::harvest_withheld_tokens_to_mint, TransferFeeAmount},
+    BaseStateWithExtensions,
+    ExtensionType,
+    StateWithExtensions,
+  },
+  state::{Account as TokenAccount, Mint},
+};
+use std::slice::Iter;
 
-use crate::state::{Bid, GameState, PlayerState};
+use crate::state::{
+  Balance,
+  CurveKind,
+  GameMode,
+  GameRules,
+  GameState,
+  PlatformConfig,
+  PriceCurve,
+  BPS_DENOMINATOR,
+};
 use crate::error::BiddingError;
+use crate::layout::load_account;
+use crate::zero_copy::GameStateView;
 
 pub fn game_pda_seeds(game_id: u64, program_id: &Pubkey) -> (Pubkey, u8) {
   Pubkey::find_program_address(&[b"game", &game_id.to_le_bytes()], program_id)
 }
 
+pub fn vault_pda_seeds(game_id: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+  Pubkey::find_program_address(&[b"vault", &game_id.to_le_bytes()], program_id)
+}
+
 pub fn bid_pda_seeds(game_id: u64, bid_number: u64, program_id: &Pubkey) -> (Pubkey, u8) {
   Pubkey::find_program_address(
     &[b"bid", &game_id.to_le_bytes(), &bid_number.to_le_bytes()],
@@ -21,6 +47,10 @@ pub fn bid_pda_seeds(game_id: u64, bid_number: u64, program_id: &Pubkey) -> (Pub
   )
 }
 
+pub fn ledger_pda_seeds(game_id: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+  Pubkey::find_program_address(&[b"ledger", &game_id.to_le_bytes()], program_id)
+}
+
 pub fn player_pda_seeds(
   game_id: u64,
   player_pubkey: &Pubkey,
@@ -33,51 +63,143 @@ pub fn player_pda_seeds(
   )
 }
 
-pub fn find_account<'a, 'b: 'a>(bidder_pubkey: &Pubkey, accounts: &'a [AccountInfo<'b>]) -> Result<&'a AccountInfo<'b>, BiddingError> {
-  for account in accounts {
-    if account.key == bidder_pubkey {
-      return Ok(account);
-    }
-  }
+pub fn stats_pda_seeds(game_id: u64, player_pubkey: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+  Pubkey::find_program_address(
+    &[b"stats", &game_id.to_le_bytes(), player_pubkey.as_ref()],
+    program_id,
+  )
+}
 
-  Err(BiddingError::AccountNotFound)
+pub fn settlement_pda_seeds(game_id: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+  Pubkey::find_program_address(&[b"settlement", &game_id.to_le_bytes()], program_id)
+}
+
+pub fn commitment_pda_seeds(game_id: u64, bidder_pubkey: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+  Pubkey::find_program_address(
+    &[b"commitment", &game_id.to_le_bytes(), bidder_pubkey.as_ref()],
+    program_id,
+  )
 }
 
-pub fn fetch_bid_history<'a, 'b: 'a>(
+pub fn auto_bid_pda_seeds(game_id: u64, player_pubkey: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+  Pubkey::find_program_address(
+    &[b"autobid", &game_id.to_le_bytes(), player_pubkey.as_ref()],
+    program_id,
+  )
+}
+
+pub fn balance_pda_seeds(owner_pubkey: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+  Pubkey::find_program_address(&[b"balance", owner_pubkey.as_ref()], program_id)
+}
+
+pub fn session_pda_seeds(owner_pubkey: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+  Pubkey::find_program_address(&[b"session", owner_pubkey.as_ref()], program_id)
+}
+
+pub fn config_pda_seeds(program_id: &Pubkey) -> (Pubkey, u8) {
+  Pubkey::find_program_address(&[b"config"], program_id)
+}
+
+pub fn treasury_pda_seeds(program_id: &Pubkey) -> (Pubkey, u8) {
+  Pubkey::find_program_address(&[b"treasury"], program_id)
+}
+
+pub fn load_config(
   program_id: &Pubkey,
-  game_id: u64,
-  total_bids: u64,  
-  accounts: &'a [AccountInfo<'b>],
-) -> Result<Vec<Bid>, BiddingError> {
-  let mut account_map: HashMap<Pubkey, &AccountInfo<'b>> = HashMap::new();
+  config_account: &AccountInfo,
+) -> Result<PlatformConfig, ProgramError> {
+  let (config_pda, _) = config_pda_seeds(program_id);
+  if *config_account.key != config_pda || config_account.owner != program_id {
+    return Err(BiddingError::InvalidConfigAccount.into());
+  }
+
+  load_account::<PlatformConfig>(program_id, config_account)
+    .map_err(|_| BiddingError::InvalidConfigAccount.into())
+}
+
+pub fn min_next_bid(highest_bid: u64, bid_multiplier_bps: u64) -> u64 {
+  let scaled = highest_bid as u128 * bid_multiplier_bps as u128;
+  scaled.div_ceil(BPS_DENOMINATOR as u128).min(u64::MAX as u128) as u64
+}
+
+pub fn rules_within_bounds(rules: &GameRules, min_rules: &GameRules, max_rules: &GameRules) -> bool {
+  (min_rules.bid_timeout..=max_rules.bid_timeout).contains(&rules.bid_timeout)
+    && (min_rules.bid_multiplier_bps..=max_rules.bid_multiplier_bps).contains(&rules.bid_multiplier_bps)
+    && (min_rules.royalty_window..=max_rules.royalty_window).contains(&rules.royalty_window)
+    && (min_rules.snipe_window..=max_rules.snipe_window).contains(&rules.snipe_window)
+    && (min_rules.snipe_extension..=max_rules.snipe_extension).contains(&rules.snipe_extension)
+    && (min_rules.max_snipe_extension..=max_rules.max_snipe_extension).contains(&rules.max_snipe_extension)
+    && (min_rules.reveal_window..=max_rules.reveal_window).contains(&rules.reveal_window)
+    && (min_rules.max_bids_per_wallet..=max_rules.max_bids_per_wallet).contains(&rules.max_bids_per_wallet)
+    && (min_rules.bid_cooldown..=max_rules.bid_cooldown).contains(&rules.bid_cooldown)
+    && (min_rules.no_self_outbid..=max_rules.no_self_outbid).contains(&rules.no_self_outbid)
+}
+
+pub fn bidding_closes_at(game_state: &GameState) -> u64 {
+  match game_state.mode {
+    GameMode::Open | GameMode::Dutch => game_state.deadline,
+    GameMode::Sealed => game_state.reveal_deadline,
+  }
+}
+
+pub fn price_curve_valid(curve: &PriceCurve, duration: u64) -> bool {
+  let step_valid = match curve.kind {
+    CurveKind::Linear => true,
+    CurveKind::Stepwise => curve.step_interval > 0 && curve.step_interval <= duration,
+  };
+
+  step_valid && curve.floor_price > 0 && curve.start_price > curve.floor_price
+}
+
+pub fn dutch_price(curve: &PriceCurve, duration: u64, elapsed: u64) -> u64 {
+  let elapsed = match curve.kind {
+    CurveKind::Linear => elapsed,
+    CurveKind::Stepwise => elapsed - elapsed % curve.step_interval,
+  };
 
-  for account_info in accounts {
-      account_map.insert(*account_info.key, account_info);
+  if duration == 0 || elapsed >= duration {
+    return curve.floor_price;
   }
 
-  let mut bid_history = Vec::with_capacity(total_bids as usize);
+  let drop = (curve.start_price - curve.floor_price) as u128 * elapsed as u128 / duration as u128;
+  curve.start_price - drop as u64
+}
 
-  for bid_number in 1..=total_bids {
-    let (bid_pda, _) = bid_pda_seeds(game_id, bid_number, program_id);
+pub fn required_bid(game: &GameStateView, current_time: u64) -> Result<u64, ProgramError> {
+  match game.mode()? {
+    GameMode::Open => Ok(min_next_bid(game.highest_bid.get(), game.rules.bid_multiplier_bps.get())),
+    GameMode::Dutch => Ok(dutch_price(
+      &game.price_curve.get()?,
+      game.rules.bid_timeout.get(),
+      current_time.saturating_sub(game.started_at.get()),
+    )),
+    GameMode::Sealed => Err(BiddingError::InvalidGameMode.into()),
+  }
+}
 
-    match account_map.get(&bid_pda) {
-      Some(account_info) => {
-        if account_info.data.borrow().is_empty() {
-          return Err(BiddingError::BidAccountNotInitialized);
-        }
+pub fn extend_deadline(game: &mut GameStateView, current_time: u64) {
+  let rules = game.rules;
+  let current_deadline = game.deadline.get();
+  let mut deadline = current_deadline.max(current_time + rules.bid_timeout.get());
+
+  if rules.snipe_window.get() > 0 && current_deadline.saturating_sub(current_time) <= rules.snipe_window.get() {
+    let extension = rules.snipe_extension.get()
+      .min(rules.max_snipe_extension.get().saturating_sub(game.snipe_extension_used.get()));
+    deadline += extension;
+    game.snipe_extension_used.set(game.snipe_extension_used.get() + extension);
+  }
 
-        let bid_data = Bid::try_from_slice(&account_info.data.borrow())
-          .map_err(|_| BiddingError::FailedToDeserializeBidData)?;
+  game.deadline.set(deadline.min(game.ends_at.get()));
+}
 
-        bid_history.push(bid_data);
-      },
-      None => {
-        return Err(BiddingError::BidAccountNotFound);
-      },
+pub fn find_account<'a, 'b: 'a>(bidder_pubkey: &Pubkey, accounts: &'a [AccountInfo<'b>]) -> Result<&'a AccountInfo<'b>, BiddingError> {
+  for account in accounts {
+    if account.key == bidder_pubkey {
+      return Ok(account);
     }
   }
 
-  Ok(bid_history)
+  Err(BiddingError::AccountNotFound)
 }
 
 pub fn transfer_from_pda<'a>(
@@ -94,52 +216,377 @@ pub fn transfer_from_pda<'a>(
   Ok(())
 }
 
- 
-pub fn deserialize_game_state(account_data: &[u8]) -> Result<GameState, ProgramError> {
-  if account_data.len() != std::mem::size_of::<GameState>() {
-      return Err(ProgramError::InvalidAccountData);
+pub fn create_pda_account<'a>(
+  program_id: &Pubkey,
+  payer_account: &AccountInfo<'a>,
+  new_account: &AccountInfo<'a>,
+  system_program: &AccountInfo<'a>,
+  space: usize,
+  signer_seeds: &[&[u8]],
+) -> ProgramResult {
+  create_owned_account(
+    program_id,
+    payer_account,
+    new_account,
+    system_program,
+    space,
+    program_id,
+    signer_seeds,
+  )
+}
+
+pub fn create_owned_account<'a>(
+  program_id: &Pubkey,
+  payer_account: &AccountInfo<'a>,
+  new_account: &AccountInfo<'a>,
+  system_program: &AccountInfo<'a>,
+  space: usize,
+  owner: &Pubkey,
+  signer_seeds: &[&[u8]],
+) -> ProgramResult {
+  invoke_signed(
+    &system_instruction::allocate(new_account.key, space as u64),
+    &[new_account.clone(), system_program.clone()],
+    &[signer_seeds],
+  )?;
+
+  invoke_signed(
+    &system_instruction::assign(new_account.key, owner),
+    &[new_account.clone(), system_program.clone()],
+    &[signer_seeds],
+  )?;
+
+  let shortfall = Rent::get()?.minimum_balance(space).saturating_sub(new_account.lamports());
+  if shortfall > 0 {
+    fund_account(program_id, payer_account, new_account, system_program, shortfall)?;
+  }
+
+  Ok(())
+}
+
+pub fn fund_account<'a>(
+  program_id: &Pubkey,
+  payer_account: &AccountInfo<'a>,
+  account: &AccountInfo<'a>,
+  system_program: &AccountInfo<'a>,
+  lamports: u64,
+) -> ProgramResult {
+  if payer_account.owner == program_id {
+    return transfer_from_pda(payer_account, account, lamports);
   }
 
-  let game_id = u64::from_le_bytes(account_data[..std::mem::size_of::<u64>()].try_into().unwrap());
-  let initial_bid_amount = u64::from_le_bytes(account_data[std::mem::size_of::<u64>()..std::mem::size_of::<u64>() * 2].try_into().unwrap());
-  let highest_bid = u64::from_le_bytes(account_data[std::mem::size_of::<u64>() * 2..std::mem::size_of::<u64>() * 3].try_into().unwrap());
-  let last_bid_time = u64::from_le_bytes(account_data[std::mem::size_of::<u64>() * 3..std::mem::size_of::<u64>() * 4].try_into().unwrap());
-  let total_bids = u64::from_le_bytes(account_data[std::mem::size_of::<u64>() * 4..std::mem::size_of::<u64>() * 5].try_into().unwrap());
+  invoke(
+    &system_instruction::transfer(payer_account.key, account.key, lamports),
+    &[payer_account.clone(), account.clone(), system_program.clone()],
+  )
+}
 
-  let last_bidder_slice = &account_data[std::mem::size_of::<u64>() * 5..std::mem::size_of::<u64>() * 5 + std::mem::size_of::<Pubkey>()];
-  let last_bidder = Pubkey::new_from_array(last_bidder_slice.try_into().map_err(|_| ProgramError::InvalidAccountData)?);
+pub fn check_balance_account(
+  program_id: &Pubkey,
+  balance_account: &AccountInfo,
+  owner: &Pubkey,
+) -> ProgramResult {
+  let (balance_pda, _) = balance_pda_seeds(owner, program_id);
+  if *balance_account.key != balance_pda {
+    return Err(BiddingError::InvalidBalanceAccount.into());
+  }
+
+  let balance = load_account::<Balance>(program_id, balance_account)
+    .map_err(|_| BiddingError::InvalidBalanceAccount)?;
+  if balance.owner != *owner {
+    return Err(BiddingError::InvalidBalanceAccount.into());
+  }
 
-  let prize_pool = u64::from_le_bytes(account_data[std::mem::size_of::<u64>() * 5 + std::mem::size_of::<Pubkey>()..std::mem::size_of::<u64>() * 6 + std::mem::size_of::<Pubkey>()].try_into().unwrap());
-  let platform_fee_percentage = u64::from_le_bytes(account_data[std::mem::size_of::<u64>() * 6 + std::mem::size_of::<Pubkey>()..std::mem::size_of::<u64>() * 7 + std::mem::size_of::<Pubkey>()].try_into().unwrap());
-  let game_ended = account_data[std::mem::size_of::<u64>() * 7 + std::mem::size_of::<Pubkey>()] != 0;
+  Ok(())
+}
 
-  Ok(GameState {
+pub fn available_lamports(account: &AccountInfo) -> Result<u64, ProgramError> {
+  let rent = Rent::get()?;
+  Ok(account.lamports().saturating_sub(rent.minimum_balance(account.data_len())))
+}
+
+pub fn close_pda<'a>(
+  account: &AccountInfo<'a>,
+  destination: &AccountInfo<'a>,
+) -> ProgramResult {
+  let lamports = account.lamports();
+  transfer_from_pda(account, destination, lamports)?;
+  account.data.borrow_mut().fill(0);
+  Ok(())
+}
+
+pub fn is_native_mint(mint: &Pubkey) -> bool {
+  *mint == Pubkey::default()
+}
+
+#[derive(Clone, Copy)]
+pub struct TokenAccounts<'a, 'b> {
+  pub token_program: &'a AccountInfo<'b>,
+  pub mint: &'a AccountInfo<'b>,
+  pub token_account: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> TokenAccounts<'a, 'b> {
+  pub fn with_token_account(self, token_account: &'a AccountInfo<'b>) -> Self {
+    TokenAccounts { token_account, ..self }
+  }
+}
+
+pub fn read_token_accounts<'a, 'b>(
+  accounts_iter: &mut Iter<'a, AccountInfo<'b>>,
+) -> Result<TokenAccounts<'a, 'b>, ProgramError> {
+  let token_program = next_account_info(accounts_iter)?;
+  let mint = next_account_info(accounts_iter)?;
+  let token_account = next_account_info(accounts_iter)?;
+
+  if spl_token_2022::check_spl_token_program_account(token_program.key).is_err() {
+    return Err(BiddingError::InvalidTokenProgram.into());
+  }
+
+  if mint.owner != token_program.key {
+    return Err(BiddingError::InvalidMint.into());
+  }
+
+  Ok(TokenAccounts { token_program, mint, token_account })
+}
+
+pub fn next_token_accounts<'a, 'b>(
+  mint: &Pubkey,
+  accounts_iter: &mut Iter<'a, AccountInfo<'b>>,
+) -> Result<Option<TokenAccounts<'a, 'b>>, ProgramError> {
+  if is_native_mint(mint) {
+    return Ok(None);
+  }
+
+  let token_accounts = read_token_accounts(accounts_iter)?;
+
+  if token_accounts.mint.key != mint {
+    return Err(BiddingError::InvalidMint.into());
+  }
+
+  Ok(Some(token_accounts))
+}
+
+pub fn token_balance(token_account: &AccountInfo) -> Result<u64, ProgramError> {
+  let data = token_account.data.borrow();
+  let account = StateWithExtensions::<TokenAccount>::unpack(&data)
+    .map_err(|_| BiddingError::InvalidTokenAccount)?;
+
+  Ok(account.base.amount)
+}
+
+pub fn mint_decimals(mint_account: &AccountInfo) -> Result<u8, ProgramError> {
+  let data = mint_account.data.borrow();
+  let mint = StateWithExtensions::<Mint>::unpack(&data)
+    .map_err(|_| BiddingError::InvalidMint)?;
+
+  Ok(mint.base.decimals)
+}
+
+pub fn check_token_account(
+  token_account: &AccountInfo,
+  mint: &Pubkey,
+  owner: &Pubkey,
+) -> ProgramResult {
+  if spl_token_2022::check_spl_token_program_account(token_account.owner).is_err() {
+    return Err(BiddingError::InvalidTokenAccount.into());
+  }
+
+  let data = token_account.data.borrow();
+  let account = StateWithExtensions::<TokenAccount>::unpack(&data)
+    .map_err(|_| BiddingError::InvalidTokenAccount)?;
+
+  if account.base.mint != *mint || account.base.owner != *owner {
+    return Err(BiddingError::InvalidTokenAccount.into());
+  }
+
+  Ok(())
+}
+
+pub fn vault_account_len(mint_account: &AccountInfo) -> Result<usize, ProgramError> {
+  let data = mint_account.data.borrow();
+  let mint = StateWithExtensions::<Mint>::unpack(&data)
+    .map_err(|_| BiddingError::InvalidMint)?;
+  let mint_extensions = mint.get_extension_types()?;
+  let account_extensions = ExtensionType::get_required_init_account_extensions(&mint_extensions);
+
+  ExtensionType::try_calculate_account_len::<TokenAccount>(&account_extensions)
+}
+
+pub fn check_vault(
+  program_id: &Pubkey,
+  game_id: u64,
+  mint: &Pubkey,
+  vault_account: &AccountInfo,
+) -> ProgramResult {
+  let (vault_pda, _) = vault_pda_seeds(game_id, program_id);
+  let owner_valid = if is_native_mint(mint) {
+    vault_account.owner == program_id
+  } else {
+    spl_token_2022::check_spl_token_program_account(vault_account.owner).is_ok()
+  };
+
+  if *vault_account.key != vault_pda || !owner_valid {
+    return Err(BiddingError::InvalidVaultAccount.into());
+  }
+
+  Ok(())
+}
+
+pub fn deposit_to_vault<'a>(
+  payer_account: &AccountInfo<'a>,
+  vault_account: &AccountInfo<'a>,
+  system_program: &AccountInfo<'a>,
+  token_accounts: Option<TokenAccounts<'_, 'a>>,
+  amount: u64,
+) -> Result<u64, ProgramError> {
+  let token_accounts = match token_accounts {
+    None => {
+      invoke(
+        &system_instruction::transfer(payer_account.key, vault_account.key, amount),
+        &[payer_account.clone(), vault_account.clone(), system_program.clone()],
+      )?;
+      return Ok(amount);
+    },
+    Some(token_accounts) => token_accounts,
+  };
+
+  let TokenAccounts { token_program, mint, token_account: source_account } = token_accounts;
+  check_token_account(source_account, mint.key, payer_account.key)?;
+
+  let decimals = mint_decimals(mint)?;
+
+  let balance_before = token_balance(vault_account)?;
+
+  invoke(
+    &spl_token_2022::instruction::transfer_checked(
+      token_program.key,
+      source_account.key,
+      mint.key,
+      vault_account.key,
+      payer_account.key,
+      &[],
+      amount,
+      decimals,
+    )?,
+    &[
+      source_account.clone(),
+      mint.clone(),
+      vault_account.clone(),
+      payer_account.clone(),
+      token_program.clone(),
+    ],
+  )?;
+
+  let balance_after = token_balance(vault_account)?;
+
+  balance_after
+    .checked_sub(balance_before)
+    .ok_or(BiddingError::InsufficientFunds.into())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn pay_from_vault<'a>(
+  program_id: &Pubkey,
+  game_id: u64,
+  game_account: &AccountInfo<'a>,
+  vault_account: &AccountInfo<'a>,
+  destination: &AccountInfo<'a>,
+  recipient: &Pubkey,
+  token_accounts: Option<TokenAccounts<'_, 'a>>,
+  amount: u64,
+) -> ProgramResult {
+  let token_accounts = match token_accounts {
+    None => {
+      if destination.key != recipient {
+        return Err(BiddingError::InvalidRecipientAccount.into());
+      }
+      return transfer_from_pda(vault_account, destination, amount);
+    },
+    Some(token_accounts) => token_accounts,
+  };
+
+  let TokenAccounts { token_program, mint, .. } = token_accounts;
+  check_token_account(destination, mint.key, recipient)?;
+
+  let decimals = mint_decimals(mint)?;
+
+  let (_, game_bump) = game_pda_seeds(game_id, program_id);
+
+  invoke_signed(
+    &spl_token_2022::instruction::transfer_checked(
+      token_program.key,
+      vault_account.key,
+      mint.key,
+      destination.key,
+      game_account.key,
+      &[],
+      amount,
+      decimals,
+    )?,
+    &[
+      vault_account.clone(),
+      mint.clone(),
+      destination.clone(),
+      game_account.clone(),
+      token_program.clone(),
+    ],
+    &[&[b"game", &game_id.to_le_bytes(), &[game_bump]]],
+  )
+}
+
+pub fn close_vault<'a>(
+  program_id: &Pubkey,
+  game_id: u64,
+  game_account: &AccountInfo<'a>,
+  vault_account: &AccountInfo<'a>,
+  destination: &AccountInfo<'a>,
+  token_accounts: Option<TokenAccounts<'_, 'a>>,
+) -> ProgramResult {
+  let token_accounts = match token_accounts {
+    None => return close_pda(vault_account, destination),
+    Some(token_accounts) => token_accounts,
+  };
+
+  pay_from_vault(
+    program_id,
     game_id,
-    initial_bid_amount,
-    highest_bid,
-    last_bid_time,
-    total_bids,
-    last_bidder,
-    prize_pool,
-    platform_fee_percentage,
-    game_ended,
-  })
-}
-
-pub fn deserialize_player_state(account_data: &[u8]) -> Result<PlayerState, ProgramError> {
-  if account_data.len() < std::mem::size_of::<PlayerState>() {
-    return Err(ProgramError::AccountDataTooSmall);
-  }
-
-  let total_bid_amount = u64::from_le_bytes(account_data[..std::mem::size_of::<u64>()].try_into().unwrap());
-  let safe = account_data[std::mem::size_of::<u64>()] != 0;
-  let royalty_earned = u64::from_le_bytes(account_data[std::mem::size_of::<u64>() + 1..std::mem::size_of::<u64>() + 9].try_into().unwrap());
-  let bid_count = u64::from_le_bytes(account_data[std::mem::size_of::<u64>() + 9..std::mem::size_of::<u64>() + 17].try_into().unwrap());
-
-  Ok(PlayerState {
-    total_bid_amount,
-    safe,
-    royalty_earned,
-    bid_count,
-  })
-}
\ No newline at end of file
+    game_account,
+    vault_account,
+    token_accounts.token_account,
+    destination.key,
+    Some(token_accounts),
+    token_balance(vault_account)?,
+  )?;
+
+  let TokenAccounts { token_program, mint, .. } = token_accounts;
+
+  let withheld_amount = {
+    let data = vault_account.data.borrow();
+    let vault = StateWithExtensions::<TokenAccount>::unpack(&data)
+      .map_err(|_| BiddingError::InvalidVaultAccount)?;
+    vault.get_extension::<TransferFeeAmount>()
+      .map_or(0, |fee_amount| u64::from(fee_amount.withheld_amount))
+  };
+
+  if withheld_amount > 0 {
+    invoke(
+      &harvest_withheld_tokens_to_mint(token_program.key, mint.key, &[vault_account.key])?,
+      &[mint.clone(), vault_account.clone(), token_program.clone()],
+    )?;
+  }
+
+  let (_, game_bump) = game_pda_seeds(game_id, program_id);
+
+  invoke_signed(
+    &spl_token_2022::instruction::close_account(
+      token_program.key,
+      vault_account.key,
+      destination.key,
+      game_account.key,
+      &[],
+    )?,
+    &[vault_account.clone(), destination.clone(), game_account.clone(), token_program.clone()],
+    &[&[b"game", &game_id.to_le_bytes(), &[game_bump]]],
+  )
+}
@@ -0,0 +1,220 @@
+use std::cell::{Ref, RefMut};
+use std::mem::size_of;
+use borsh::BorshDeserialize;
+use bytemuck::{Pod, Zeroable};
+use solana_program::{
+  account_info::AccountInfo,
+  program_error::ProgramError,
+  pubkey::Pubkey,
+};
+use crate::layout::{check_header, AccountLayout, FixedSize, HEADER_LEN};
+use crate::state::{
+  Bid,
+  CurveKind,
+  GameMode,
+  GameRules,
+  GameState,
+  GameStatus,
+  Ledger,
+  PlayerState,
+  PriceCurve,
+};
+
+#[repr(transparent)]
+#[derive(Pod, Zeroable, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct PodU64([u8; 8]);
+
+impl PodU64 {
+  pub fn get(&self) -> u64 {
+    u64::from_le_bytes(self.0)
+  }
+
+  pub fn set(&mut self, value: u64) {
+    self.0 = value.to_le_bytes();
+  }
+}
+
+impl From<u64> for PodU64 {
+  fn from(value: u64) -> Self {
+    Self(value.to_le_bytes())
+  }
+}
+
+#[repr(transparent)]
+#[derive(Pod, Zeroable, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct PodBool(u8);
+
+impl PodBool {
+  pub fn get(&self) -> bool {
+    self.0 != 0
+  }
+
+  pub fn set(&mut self, value: bool) {
+    self.0 = value as u8;
+  }
+}
+
+impl From<bool> for PodBool {
+  fn from(value: bool) -> Self {
+    Self(value as u8)
+  }
+}
+
+pub trait ZeroCopy: Pod {
+  type Layout: AccountLayout;
+}
+
+#[repr(C)]
+#[derive(Pod, Zeroable, Clone, Copy, Debug)]
+pub struct GameRulesView {
+  pub bid_timeout: PodU64,
+  pub bid_multiplier_bps: PodU64,
+  pub royalty_window: PodU64,
+  pub snipe_window: PodU64,
+  pub snipe_extension: PodU64,
+  pub max_snipe_extension: PodU64,
+  pub reveal_window: PodU64,
+  pub max_bids_per_wallet: PodU64,
+  pub bid_cooldown: PodU64,
+  pub no_self_outbid: PodBool,
+}
+
+#[repr(C)]
+#[derive(Pod, Zeroable, Clone, Copy, Debug)]
+pub struct PriceCurveView {
+  pub kind: u8,
+  pub start_price: PodU64,
+  pub floor_price: PodU64,
+  pub step_interval: PodU64,
+}
+
+impl PriceCurveView {
+  pub fn get(&self) -> Result<PriceCurve, ProgramError> {
+    Ok(PriceCurve {
+      kind: CurveKind::try_from_slice(&[self.kind])?,
+      start_price: self.start_price.get(),
+      floor_price: self.floor_price.get(),
+      step_interval: self.step_interval.get(),
+    })
+  }
+}
+
+#[repr(C)]
+#[derive(Pod, Zeroable, Clone, Copy, Debug)]
+pub struct GameStateView {
+  pub game_id: PodU64,
+  pub initial_bid_amount: PodU64,
+  pub highest_bid: PodU64,
+  pub last_bid_time: PodU64,
+  pub total_bids: PodU64,
+  pub last_bidder: Pubkey,
+  pub prize_pool: PodU64,
+  pub platform_fee_percentage: PodU64,
+  pub rules: GameRulesView,
+  pub deadline: PodU64,
+  pub snipe_extension_used: PodU64,
+  pub mint: Pubkey,
+  pub mode: u8,
+  pub reveal_deadline: PodU64,
+  pub escrowed_deposits: PodU64,
+  pub price_curve: PriceCurveView,
+  pub started_at: PodU64,
+  pub auto_bid_escrow: PodU64,
+  pub ends_at: PodU64,
+  pub status: u8,
+}
+
+impl GameStateView {
+  pub fn mode(&self) -> Result<GameMode, ProgramError> {
+    Ok(GameMode::try_from_slice(&[self.mode])?)
+  }
+
+  pub fn status(&self) -> Result<GameStatus, ProgramError> {
+    Ok(GameStatus::try_from_slice(&[self.status])?)
+  }
+
+  pub fn revealed_leader(&self) -> Result<Option<Pubkey>, ProgramError> {
+    let pending = self.mode()? == GameMode::Sealed
+      && self.total_bids.get() == 1
+      && self.highest_bid.get() > self.initial_bid_amount.get();
+
+    Ok(pending.then_some(self.last_bidder))
+  }
+}
+
+impl ZeroCopy for GameStateView {
+  type Layout = GameState;
+}
+
+#[repr(C)]
+#[derive(Pod, Zeroable, Clone, Copy, Debug)]
+pub struct PlayerStateView {
+  pub total_bid_amount: PodU64,
+  pub safe: PodBool,
+  pub royalty_earned: PodU64,
+  pub bid_count: PodU64,
+  pub claimed: PodBool,
+  pub bidder: Pubkey,
+  pub payer: Pubkey,
+}
+
+impl ZeroCopy for PlayerStateView {
+  type Layout = PlayerState;
+}
+
+#[repr(C)]
+#[derive(Pod, Zeroable, Clone, Copy, Debug)]
+pub struct BidView {
+  pub bidder: Pubkey,
+  pub amount: PodU64,
+  pub timestamp: PodU64,
+  pub payer: Pubkey,
+}
+
+#[repr(C)]
+#[derive(Pod, Zeroable, Clone, Copy, Debug)]
+pub struct LedgerView {
+  pub game_id: PodU64,
+  pub len: PodU64,
+  pub payer: Pubkey,
+}
+
+const _: () = assert!(size_of::<GameRulesView>() == GameRules::SIZE);
+const _: () = assert!(size_of::<PriceCurveView>() == PriceCurve::SIZE);
+const _: () = assert!(size_of::<GameStateView>() == GameState::SIZE);
+const _: () = assert!(size_of::<PlayerStateView>() == PlayerState::SIZE);
+const _: () = assert!(size_of::<BidView>() == Bid::SIZE);
+const _: () = assert!(size_of::<LedgerView>() == Ledger::SIZE);
+
+pub fn load_view<'a, V: ZeroCopy>(
+  program_id: &Pubkey,
+  account: &'a AccountInfo,
+) -> Result<Ref<'a, V>, ProgramError> {
+  let data = account.try_borrow_data()?;
+  check_header::<V::Layout>(program_id, account, &data)?;
+
+  Ok(Ref::map(data, |data| bytemuck::from_bytes(&data[HEADER_LEN..])))
+}
+
+pub fn load_view_mut<'a, V: ZeroCopy>(
+  program_id: &Pubkey,
+  account: &'a AccountInfo,
+) -> Result<RefMut<'a, V>, ProgramError> {
+  let data = account.try_borrow_mut_data()?;
+  check_header::<V::Layout>(program_id, account, &data)?;
+
+  Ok(RefMut::map(data, |data| bytemuck::from_bytes_mut(&mut data[HEADER_LEN..])))
+}
+
+pub fn init_view<'a, V: ZeroCopy>(account: &'a AccountInfo) -> Result<RefMut<'a, V>, ProgramError> {
+  let mut data = account.try_borrow_mut_data()?;
+
+  if data.len() != V::Layout::SPACE {
+    return Err(ProgramError::InvalidAccountData);
+  }
+
+  data[0] = V::Layout::KIND as u8;
+  data[1] = V::Layout::VERSION;
+
+  Ok(RefMut::map(data, |data| bytemuck::from_bytes_mut(&mut data[HEADER_LEN..])))
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use common::{custom_error, start_with_config, Fixture};
+use programs::error::BiddingError;
+use programs::layout::AccountLayout;
+use programs::state::{
+  AccountKind,
+  GameState,
+  PlayerState,
+  PlayerStats,
+  GAME_ACCOUNT_SIZE,
+  PLAYER_ACCOUNT_SIZE,
+  STATS_ACCOUNT_SIZE,
+};
+use solana_program::{pubkey::Pubkey, rent::Rent};
+use solana_sdk::signature::{Keypair, Signer};
+
+const GAME_ID: u64 = 21;
+const BID: u64 = 110_000_000;
+
+async fn contested_game() -> (Fixture, Keypair) {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let bidder = fixture.wallet().await;
+
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+  fixture.place_bid(&bidder, GAME_ID, BID, 2).await.unwrap();
+
+  (fixture, bidder)
+}
+
+async fn assert_layout<T: AccountLayout>(fixture: &mut Fixture, key: Pubkey, size: usize) {
+  let account = fixture.account(key).await.unwrap();
+
+  assert_eq!(T::SPACE, size);
+  assert_eq!(account.data.len(), size);
+  assert_eq!(account.data[..2], [T::KIND as u8, T::VERSION]);
+  assert_eq!(account.lamports, Rent::default().minimum_balance(size));
+}
+
+#[tokio::test]
+async fn created_accounts_match_their_declared_layouts() {
+  let (mut fixture, bidder) = contested_game().await;
+  let game_key = fixture.game_key(GAME_ID);
+  let player_key = fixture.player_key(GAME_ID, &bidder.pubkey(), 2);
+  let stats_key = fixture.stats_key(GAME_ID, &bidder.pubkey());
+
+  assert_layout::<GameState>(&mut fixture, game_key, GAME_ACCOUNT_SIZE).await;
+  assert_layout::<PlayerState>(&mut fixture, player_key, PLAYER_ACCOUNT_SIZE).await;
+  assert_layout::<PlayerStats>(&mut fixture, stats_key, STATS_ACCOUNT_SIZE).await;
+
+  let player_state = fixture.state::<PlayerState>(player_key).await;
+  assert_eq!(player_state.total_bid_amount, BID);
+  assert_eq!(player_state.bidder, bidder.pubkey());
+}
+
+#[tokio::test]
+async fn accounts_with_unknown_versions_are_rejected() {
+  let (mut fixture, _) = contested_game().await;
+  let game_key = fixture.game_key(GAME_ID);
+
+  let mut account = fixture.account(game_key).await.unwrap();
+  assert_eq!(account.data[0], AccountKind::Game as u8);
+  account.data[1] = GameState::VERSION + 1;
+  fixture.context.set_account(&game_key, &account.into());
+
+  let bidder = fixture.wallet().await;
+  assert_eq!(
+    fixture.place_bid(&bidder, GAME_ID, 121_000_000, 3).await.unwrap_err(),
+    custom_error(BiddingError::UnsupportedAccountVersion),
+  );
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use common::{custom_error, start_with_config, Fixture, INITIAL_BID};
+use programs::error::BiddingError;
+use programs::state::{AccountKind, PlayerState};
+use solana_program::system_program;
+use solana_sdk::signature::{Keypair, Signer};
+
+const GAME_ID: u64 = 22;
+const BID: u64 = 110_000_000;
+
+async fn contested_game() -> (Fixture, Keypair, Keypair) {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let bidder = fixture.wallet().await;
+
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+  fixture.place_bid(&bidder, GAME_ID, BID, 2).await.unwrap();
+
+  (fixture, creator, bidder)
+}
+
+#[tokio::test]
+async fn end_game_pays_winner_wallet_and_tags_player_account() {
+  let (mut fixture, creator, bidder) = contested_game().await;
+  let player_key = fixture.player_key(GAME_ID, &bidder.pubkey(), 2);
+
+  fixture.warp(601).await;
+  let bidder_lamports = fixture.lamports(bidder.pubkey()).await;
+  fixture.end_game(&creator, GAME_ID, &bidder.pubkey(), 2).await.unwrap();
+
+  let pool = INITIAL_BID + BID;
+  assert_eq!(fixture.lamports(bidder.pubkey()).await, bidder_lamports + pool - pool / 10);
+
+  let wallet = fixture.account(bidder.pubkey()).await.unwrap();
+  assert!(wallet.data.is_empty());
+
+  let player_account = fixture.account(player_key).await.unwrap();
+  assert_eq!(player_account.data[0], AccountKind::Player as u8);
+
+  let player_state = fixture.state::<PlayerState>(player_key).await;
+  assert!(player_state.claimed);
+}
+
+#[tokio::test]
+async fn loaders_reject_foreign_kinds_and_owners() {
+  let (mut fixture, creator, bidder) = contested_game().await;
+  let game_key = fixture.game_key(GAME_ID);
+  let player_key = fixture.player_key(GAME_ID, &bidder.pubkey(), 2);
+
+  let game_account = fixture.account(game_key).await.unwrap();
+  let mut disguised = game_account.clone();
+  disguised.data[0] = AccountKind::Player as u8;
+  fixture.context.set_account(&game_key, &disguised.into());
+
+  let rival = fixture.wallet().await;
+  assert_eq!(
+    fixture.place_bid(&rival, GAME_ID, 121_000_000, 3).await.unwrap_err(),
+    custom_error(BiddingError::InvalidAccountType),
+  );
+
+  fixture.context.set_account(&game_key, &game_account.into());
+  let mut player_account = fixture.account(player_key).await.unwrap();
+  player_account.owner = system_program::id();
+  fixture.context.set_account(&player_key, &player_account.into());
+
+  fixture.warp(601).await;
+  assert_eq!(
+    fixture.end_game(&creator, GAME_ID, &bidder.pubkey(), 2).await.unwrap_err(),
+    custom_error(BiddingError::InvalidAccountOwner),
+  );
+}
@@ -0,0 +1,75 @@
+mod common;
+
+use common::{custom_error, default_rules, start_with_config, Fixture, INITIAL_BID};
+use programs::error::BiddingError;
+use programs::state::{GameMode, GameRules, GameState};
+
+const GAME_ID: u64 = 10;
+const BIDS: [u64; 3] = [110_000_000, 121_000_000, 134_000_000];
+
+fn snipe_rules() -> GameRules {
+  GameRules {
+    snipe_window: 60,
+    snipe_extension: 120,
+    max_snipe_extension: 200,
+    ..default_rules()
+  }
+}
+
+async fn game_state(fixture: &mut Fixture) -> GameState {
+  fixture.state::<GameState>(fixture.game_key(GAME_ID)).await
+}
+
+#[tokio::test]
+async fn late_bids_extend_deadline_up_to_the_cap() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  fixture
+    .create_game_with(&creator, GAME_ID, INITIAL_BID, Some(snipe_rules()), GameMode::Open, None, None)
+    .await
+    .unwrap();
+  let started_at = fixture.now().await;
+
+  let expected = [
+    (570, started_at + 570 + 600 + 120, 120),
+    (1250, started_at + 1250 + 600 + 80, 200),
+    (1900, started_at + 1900 + 600, 200),
+  ];
+  for (index, (at, deadline, used)) in expected.into_iter().enumerate() {
+    let elapsed = fixture.now().await - started_at;
+    fixture.warp(at - elapsed).await;
+
+    let bidder = fixture.wallet().await;
+    fixture.place_bid(&bidder, GAME_ID, BIDS[index], index as u64 + 2).await.unwrap();
+
+    let game_state = game_state(&mut fixture).await;
+    assert_eq!(game_state.deadline, deadline);
+    assert_eq!(game_state.snipe_extension_used, used);
+  }
+}
+
+#[tokio::test]
+async fn bids_outside_the_snipe_window_do_not_extend() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let bidder = fixture.wallet().await;
+  fixture
+    .create_game_with(&creator, GAME_ID, INITIAL_BID, Some(snipe_rules()), GameMode::Open, None, None)
+    .await
+    .unwrap();
+
+  fixture.warp(100).await;
+  fixture.place_bid(&bidder, GAME_ID, BIDS[0], 2).await.unwrap();
+
+  let now = fixture.now().await;
+  let game_state = game_state(&mut fixture).await;
+  assert_eq!(game_state.deadline, now + 600);
+  assert_eq!(game_state.snipe_extension_used, 0);
+
+  fixture.warp(601).await;
+  let late_bidder = fixture.wallet().await;
+  assert_eq!(
+    fixture.place_bid(&late_bidder, GAME_ID, BIDS[1], 3).await.unwrap_err(),
+    custom_error(BiddingError::BiddingClosed),
+  );
+}
@@ -0,0 +1,144 @@
+mod common;
+
+use common::{custom_error, start_with_config, Fixture, INITIAL_BID};
+use programs::error::BiddingError;
+use programs::instructions::BiddingInstruction;
+use programs::state::{AutoBid, GameState, AUTO_BID_ACCOUNT_SIZE};
+use solana_program::{
+  instruction::{AccountMeta, InstructionError},
+  pubkey::Pubkey,
+  rent::Rent,
+  system_program,
+};
+use solana_sdk::{
+  signature::{Keypair, Signer},
+  transaction::TransactionError,
+};
+
+const GAME_ID: u64 = 15;
+const RIVAL_BID: u64 = 110_000_000;
+const AUTO_BID: u64 = 121_000_000;
+
+async fn crank_auto_bid(
+  fixture: &mut Fixture,
+  cranker: &Keypair,
+  player: &Pubkey,
+  bid_count: u64,
+) -> Result<(), TransactionError> {
+  let accounts = vec![
+    AccountMeta::new_readonly(system_program::id(), false),
+    AccountMeta::new(fixture.game_key(GAME_ID), false),
+    AccountMeta::new(cranker.pubkey(), true),
+    AccountMeta::new(fixture.auto_bid_key(GAME_ID, player), false),
+    AccountMeta::new(fixture.ledger_key(GAME_ID), false),
+    AccountMeta::new(fixture.player_key(GAME_ID, player, bid_count), false),
+    AccountMeta::new(fixture.stats_key(GAME_ID, player), false),
+  ];
+
+  fixture.process(&BiddingInstruction::CrankAutoBid { game_id: GAME_ID, bid_count }, accounts, &[cranker]).await
+}
+
+#[tokio::test]
+async fn dust_auto_bid_cannot_block_cancel_game() {
+  let rent = Rent::default();
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let griefer = fixture.wallet().await;
+
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+  fixture.set_auto_bid(&griefer, GAME_ID, 1).await.unwrap();
+  fixture.warp(601).await;
+
+  assert_eq!(
+    fixture.cancel_game(&creator.pubkey(), GAME_ID).await.unwrap_err(),
+    custom_error(BiddingError::AutoBidsOutstanding),
+  );
+
+  let griefer_lamports = fixture.lamports(griefer.pubkey()).await;
+  fixture.cancel_auto_bid(&griefer.pubkey(), GAME_ID, &[]).await.unwrap();
+
+  assert!(fixture.account(fixture.auto_bid_key(GAME_ID, &griefer.pubkey())).await.is_none());
+  assert_eq!(
+    fixture.lamports(griefer.pubkey()).await,
+    griefer_lamports + rent.minimum_balance(AUTO_BID_ACCOUNT_SIZE) + 1,
+  );
+
+  let creator_lamports = fixture.lamports(creator.pubkey()).await;
+  fixture.cancel_game(&creator.pubkey(), GAME_ID).await.unwrap();
+
+  assert!(fixture.account(fixture.game_key(GAME_ID)).await.is_none());
+  assert!(fixture.lamports(creator.pubkey()).await > creator_lamports + INITIAL_BID);
+}
+
+#[tokio::test]
+async fn cancel_auto_bid_requires_owner_while_bidding_is_open() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let player = fixture.wallet().await;
+
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+  fixture.set_auto_bid(&player, GAME_ID, 500_000_000).await.unwrap();
+
+  assert_eq!(
+    fixture.cancel_auto_bid(&player.pubkey(), GAME_ID, &[]).await.unwrap_err(),
+    TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature),
+  );
+
+  let player_lamports = fixture.lamports(player.pubkey()).await;
+  fixture.cancel_auto_bid(&player.pubkey(), GAME_ID, &[&player]).await.unwrap();
+
+  assert!(fixture.lamports(player.pubkey()).await > player_lamports + 500_000_000);
+}
+
+#[tokio::test]
+async fn crank_auto_bid_outbids_rival_from_budget() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let player = fixture.wallet().await;
+  let rival = fixture.wallet().await;
+  let cranker = fixture.wallet().await;
+
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+  fixture.set_auto_bid(&player, GAME_ID, 200_000_000).await.unwrap();
+  fixture.place_bid(&rival, GAME_ID, RIVAL_BID, 2).await.unwrap();
+
+  let vault_lamports = fixture.lamports(fixture.vault_key(GAME_ID)).await;
+  crank_auto_bid(&mut fixture, &cranker, &player.pubkey(), 3).await.unwrap();
+
+  let game_state = fixture.state::<GameState>(fixture.game_key(GAME_ID)).await;
+  assert_eq!(game_state.last_bidder, player.pubkey());
+  assert_eq!(game_state.highest_bid, AUTO_BID);
+  assert_eq!(game_state.prize_pool, INITIAL_BID + RIVAL_BID + AUTO_BID);
+  assert_eq!(game_state.auto_bid_escrow, 200_000_000 - AUTO_BID);
+  assert_eq!(fixture.lamports(fixture.vault_key(GAME_ID)).await, vault_lamports);
+
+  let auto_bid = fixture.state::<AutoBid>(fixture.auto_bid_key(GAME_ID, &player.pubkey())).await;
+  assert_eq!(auto_bid.budget, 200_000_000 - AUTO_BID);
+
+  assert_eq!(
+    crank_auto_bid(&mut fixture, &cranker, &player.pubkey(), 4).await.unwrap_err(),
+    custom_error(BiddingError::AutoBidNotNeeded),
+  );
+}
+
+#[tokio::test]
+async fn crank_auto_bid_rejects_stale_counts_and_short_budgets() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let player = fixture.wallet().await;
+  let rival = fixture.wallet().await;
+  let cranker = fixture.wallet().await;
+
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+  fixture.set_auto_bid(&player, GAME_ID, AUTO_BID - 1).await.unwrap();
+  fixture.place_bid(&rival, GAME_ID, RIVAL_BID, 2).await.unwrap();
+
+  assert_eq!(
+    crank_auto_bid(&mut fixture, &cranker, &player.pubkey(), 2).await.unwrap_err(),
+    custom_error(BiddingError::BidCountMismatch),
+  );
+  assert_eq!(
+    crank_auto_bid(&mut fixture, &cranker, &player.pubkey(), 3).await.unwrap_err(),
+    custom_error(BiddingError::AutoBidBudgetExhausted),
+  );
+}
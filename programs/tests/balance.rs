@@ -0,0 +1,104 @@
+mod common;
+
+use common::{custom_error, start_with_config, Fixture, INITIAL_BID};
+use programs::error::BiddingError;
+use programs::instructions::BiddingInstruction;
+use programs::state::{
+  GameState,
+  BALANCE_ACCOUNT_SIZE,
+  BID_RECORD_SIZE,
+  LEDGER_ACCOUNT_SIZE,
+  PLAYER_ACCOUNT_SIZE,
+  STATS_ACCOUNT_SIZE,
+};
+use solana_program::{instruction::AccountMeta, rent::Rent, system_program};
+use solana_sdk::{
+  signature::{Keypair, Signer},
+  transaction::TransactionError,
+};
+
+const GAME_ID: u64 = 16;
+const DEPOSIT: u64 = 500_000_000;
+const BID: u64 = 110_000_000;
+
+fn bid_charge() -> u64 {
+  let rent = Rent::default();
+  let ledger_growth = rent.minimum_balance(LEDGER_ACCOUNT_SIZE + 2 * BID_RECORD_SIZE)
+    - rent.minimum_balance(LEDGER_ACCOUNT_SIZE + BID_RECORD_SIZE);
+
+  BID + rent.minimum_balance(PLAYER_ACCOUNT_SIZE) + ledger_growth + rent.minimum_balance(STATS_ACCOUNT_SIZE)
+}
+
+async fn withdraw(fixture: &mut Fixture, owner: &Keypair, amount: u64) -> Result<(), TransactionError> {
+  let accounts = vec![
+    AccountMeta::new(owner.pubkey(), true),
+    AccountMeta::new(fixture.balance_key(&owner.pubkey()), false),
+  ];
+
+  fixture.process(&BiddingInstruction::Withdraw { amount }, accounts, &[owner]).await
+}
+
+async fn bid_from_balance(fixture: &mut Fixture, bidder: &Keypair, bid_amount: u64) -> Result<(), TransactionError> {
+  let bidder_key = bidder.pubkey();
+  let accounts = vec![
+    AccountMeta::new_readonly(system_program::id(), false),
+    AccountMeta::new(fixture.game_key(GAME_ID), false),
+    AccountMeta::new(bidder_key, true),
+    AccountMeta::new(fixture.balance_key(&bidder_key), false),
+    AccountMeta::new(fixture.ledger_key(GAME_ID), false),
+    AccountMeta::new(fixture.player_key(GAME_ID, &bidder_key, 2), false),
+    AccountMeta::new(fixture.vault_key(GAME_ID), false),
+    AccountMeta::new(fixture.stats_key(GAME_ID, &bidder_key), false),
+  ];
+  let instruction = BiddingInstruction::PlaceBidFromBalance { game_id: GAME_ID, bid_amount, bid_count: 2 };
+
+  fixture.process(&instruction, accounts, &[bidder]).await
+}
+
+#[tokio::test]
+async fn deposit_funds_bids_and_remainder_withdraws() {
+  let balance_rent = Rent::default().minimum_balance(BALANCE_ACCOUNT_SIZE);
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let bidder = fixture.wallet().await;
+  let balance_key = fixture.balance_key(&bidder.pubkey());
+
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+  fixture.deposit(&bidder, DEPOSIT).await.unwrap();
+  assert_eq!(fixture.lamports(balance_key).await, balance_rent + DEPOSIT);
+
+  let bidder_lamports = fixture.lamports(bidder.pubkey()).await;
+  bid_from_balance(&mut fixture, &bidder, BID).await.unwrap();
+
+  assert_eq!(fixture.lamports(bidder.pubkey()).await, bidder_lamports);
+  assert_eq!(fixture.lamports(balance_key).await, balance_rent + DEPOSIT - bid_charge());
+
+  let game_state = fixture.state::<GameState>(fixture.game_key(GAME_ID)).await;
+  assert_eq!(game_state.last_bidder, bidder.pubkey());
+  assert_eq!(game_state.prize_pool, INITIAL_BID + BID);
+
+  let remainder = DEPOSIT - bid_charge();
+  withdraw(&mut fixture, &bidder, remainder).await.unwrap();
+
+  assert_eq!(fixture.lamports(bidder.pubkey()).await, bidder_lamports + remainder);
+  assert_eq!(fixture.lamports(balance_key).await, balance_rent);
+}
+
+#[tokio::test]
+async fn balance_rejects_overdrafts() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let bidder = fixture.wallet().await;
+
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+  fixture.deposit(&bidder, BID).await.unwrap();
+
+  assert_eq!(
+    bid_from_balance(&mut fixture, &bidder, BID).await.unwrap_err(),
+    custom_error(BiddingError::InsufficientBalance),
+  );
+  assert_eq!(
+    withdraw(&mut fixture, &bidder, BID + 1).await.unwrap_err(),
+    custom_error(BiddingError::InsufficientBalance),
+  );
+}
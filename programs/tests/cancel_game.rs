@@ -0,0 +1,67 @@
+mod common;
+
+use common::{custom_error, start_with_config, INITIAL_BID};
+use programs::error::BiddingError;
+use solana_sdk::signature::Signer;
+
+const GAME_ID: u64 = 4;
+
+#[tokio::test]
+async fn cancel_game_refunds_creator_and_closes_accounts() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let creator_key = creator.pubkey();
+
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+  fixture.warp(601).await;
+
+  let keys = [
+    fixture.game_key(GAME_ID),
+    fixture.player_key(GAME_ID, &creator_key, 1),
+    fixture.ledger_key(GAME_ID),
+    fixture.stats_key(GAME_ID, &creator_key),
+    fixture.vault_key(GAME_ID),
+  ];
+  let mut held = 0;
+  for key in keys {
+    held += fixture.lamports(key).await;
+  }
+  assert!(held > INITIAL_BID);
+  let creator_lamports = fixture.lamports(creator_key).await;
+
+  fixture.cancel_game(&creator_key, GAME_ID).await.unwrap();
+
+  assert_eq!(fixture.lamports(creator_key).await, creator_lamports + held);
+  for key in keys {
+    assert!(fixture.account(key).await.is_none());
+  }
+}
+
+#[tokio::test]
+async fn cancel_game_rejects_contested_game() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let bidder = fixture.wallet().await;
+
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+  fixture.place_bid(&bidder, GAME_ID, 110_000_000, 2).await.unwrap();
+  fixture.warp(601).await;
+
+  assert_eq!(
+    fixture.cancel_game(&creator.pubkey(), GAME_ID).await.unwrap_err(),
+    custom_error(BiddingError::GameContested),
+  );
+}
+
+#[tokio::test]
+async fn cancel_game_rejects_game_within_timeout() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+
+  assert_eq!(
+    fixture.cancel_game(&creator.pubkey(), GAME_ID).await.unwrap_err(),
+    custom_error(BiddingError::GameStillActive),
+  );
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use common::{custom_error, start_with_config, Fixture, INITIAL_BID};
+use programs::error::BiddingError;
+use programs::state::PlayerState;
+use solana_program::instruction::AccountMeta;
+use solana_sdk::signature::{Keypair, Signer};
+
+const GAME_ID: u64 = 3;
+const BIDS: [u64; 3] = [110_000_000, 121_000_000, 134_000_000];
+
+struct Game {
+  fixture: Fixture,
+  creator: Keypair,
+  bidders: Vec<Keypair>,
+}
+
+async fn settled_game() -> Game {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+
+  let mut bidders = Vec::new();
+  for (index, amount) in BIDS.iter().enumerate() {
+    let bidder = fixture.wallet().await;
+    fixture.place_bid(&bidder, GAME_ID, *amount, index as u64 + 2).await.unwrap();
+    bidders.push(bidder);
+  }
+
+  fixture.warp(601).await;
+  let winner = bidders[2].pubkey();
+  fixture.end_game(&creator, GAME_ID, &winner, 4).await.unwrap();
+
+  let accounts = vec![
+    AccountMeta::new(fixture.player_key(GAME_ID, &creator.pubkey(), 1), false),
+    AccountMeta::new(winner, false),
+    AccountMeta::new(fixture.player_key(GAME_ID, &winner, 4), false),
+    AccountMeta::new(creator.pubkey(), false),
+  ];
+  fixture.settle_batch(GAME_ID, 0, 1, accounts).await.unwrap();
+
+  Game { fixture, creator, bidders }
+}
+
+#[tokio::test]
+async fn claim_royalty_pays_bid_and_royalty_once() {
+  let Game { mut fixture, creator, .. } = settled_game().await;
+  let creator_player = fixture.player_key(GAME_ID, &creator.pubkey(), 1);
+  let creator_lamports = fixture.lamports(creator.pubkey()).await;
+  let vault_lamports = fixture.lamports(fixture.vault_key(GAME_ID)).await;
+
+  fixture.claim_royalty(&creator, GAME_ID, 1).await.unwrap();
+
+  assert_eq!(fixture.lamports(creator.pubkey()).await, creator_lamports + INITIAL_BID + BIDS[1]);
+  assert_eq!(fixture.lamports(fixture.vault_key(GAME_ID)).await, vault_lamports - INITIAL_BID - BIDS[1]);
+  assert!(fixture.state::<PlayerState>(creator_player).await.claimed);
+
+  assert_eq!(
+    fixture.claim_royalty(&creator, GAME_ID, 1).await.unwrap_err(),
+    custom_error(BiddingError::RoyaltyAlreadyClaimed),
+  );
+}
+
+#[tokio::test]
+async fn claim_royalty_rejects_bids_inside_the_royalty_window() {
+  let Game { mut fixture, bidders, .. } = settled_game().await;
+
+  assert_eq!(
+    fixture.claim_royalty(&bidders[0], GAME_ID, 2).await.unwrap_err(),
+    custom_error(BiddingError::NothingToClaim),
+  );
+}
@@ -0,0 +1,177 @@
+mod common;
+
+use common::{custom_error, start_with_config, Fixture};
+use programs::error::BiddingError;
+use programs::instructions::BiddingInstruction;
+use programs::state::{BID_RECORD_SIZE, LEDGER_ACCOUNT_SIZE, PLAYER_ACCOUNT_SIZE, STATS_ACCOUNT_SIZE};
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey, rent::Rent};
+use solana_sdk::{
+  signature::{Keypair, Signer},
+  transaction::TransactionError,
+};
+
+const GAME_ID: u64 = 7;
+
+struct Game {
+  fixture: Fixture,
+  creator: Keypair,
+  bidder: Keypair,
+}
+
+async fn ended_game() -> Game {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let bidder = fixture.wallet().await;
+
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+  fixture.place_bid(&bidder, GAME_ID, 110_000_000, 2).await.unwrap();
+  fixture.warp(601).await;
+  fixture.end_game(&creator, GAME_ID, &bidder.pubkey(), 2).await.unwrap();
+
+  Game { fixture, creator, bidder }
+}
+
+async fn close_accounts(fixture: &mut Fixture, pairs: &[(Pubkey, Pubkey)]) -> Result<(), TransactionError> {
+  let mut accounts = vec![
+    AccountMeta::new_readonly(fixture.game_key(GAME_ID), false),
+    AccountMeta::new(fixture.ledger_key(GAME_ID), false),
+  ];
+  for (account, payer) in pairs {
+    accounts.push(AccountMeta::new(*account, false));
+    accounts.push(AccountMeta::new(*payer, false));
+  }
+
+  fixture.process(&BiddingInstruction::CloseAccounts { game_id: GAME_ID }, accounts, &[]).await
+}
+
+async fn close_ledger(fixture: &mut Fixture, recipient: Pubkey) -> Result<(), TransactionError> {
+  let accounts = vec![
+    AccountMeta::new(fixture.ledger_key(GAME_ID), false),
+    AccountMeta::new(recipient, false),
+  ];
+
+  fixture.process(&BiddingInstruction::CloseLedger { game_id: GAME_ID }, accounts, &[]).await
+}
+
+#[tokio::test]
+async fn close_ledger_requires_player_accounts_closed() {
+  let Game { mut fixture, creator, .. } = ended_game().await;
+
+  assert_eq!(
+    close_ledger(&mut fixture, creator.pubkey()).await.unwrap_err(),
+    custom_error(BiddingError::PlayerAccountsOutstanding),
+  );
+}
+
+#[tokio::test]
+async fn close_accounts_refunds_each_record_as_its_player_account_closes() {
+  let rent = Rent::default();
+  let player_rent = rent.minimum_balance(PLAYER_ACCOUNT_SIZE);
+  let record_rent = rent.minimum_balance(BID_RECORD_SIZE) - rent.minimum_balance(0);
+  let Game { mut fixture, creator, bidder } = ended_game().await;
+  let (creator, bidder) = (creator.pubkey(), bidder.pubkey());
+  let bidder_player = fixture.player_key(GAME_ID, &bidder, 2);
+  let ledger_key = fixture.ledger_key(GAME_ID);
+  let bidder_lamports = fixture.lamports(bidder).await;
+
+  close_accounts(&mut fixture, &[(bidder_player, bidder)]).await.unwrap();
+
+  assert_eq!(fixture.lamports(bidder).await, bidder_lamports + player_rent + record_rent);
+  assert_eq!(fixture.lamports(bidder_player).await, 0);
+
+  let ledger = fixture.account(ledger_key).await.unwrap();
+  assert_eq!(ledger.data.len(), LEDGER_ACCOUNT_SIZE + BID_RECORD_SIZE);
+  assert_eq!(ledger.lamports, rent.minimum_balance(LEDGER_ACCOUNT_SIZE + BID_RECORD_SIZE));
+
+  assert_eq!(
+    close_ledger(&mut fixture, creator).await.unwrap_err(),
+    custom_error(BiddingError::PlayerAccountsOutstanding),
+  );
+}
+
+#[tokio::test]
+async fn close_accounts_closes_ledger_with_its_last_record() {
+  let rent = Rent::default();
+  let player_rent = rent.minimum_balance(PLAYER_ACCOUNT_SIZE);
+  let record_rent = rent.minimum_balance(BID_RECORD_SIZE) - rent.minimum_balance(0);
+  let Game { mut fixture, creator, bidder } = ended_game().await;
+  let (creator, bidder) = (creator.pubkey(), bidder.pubkey());
+  let creator_player = fixture.player_key(GAME_ID, &creator, 1);
+  let bidder_player = fixture.player_key(GAME_ID, &bidder, 2);
+  let ledger_key = fixture.ledger_key(GAME_ID);
+  let creator_lamports = fixture.lamports(creator).await;
+  let bidder_lamports = fixture.lamports(bidder).await;
+
+  close_accounts(&mut fixture, &[(creator_player, creator), (bidder_player, bidder)]).await.unwrap();
+
+  assert_eq!(fixture.lamports(ledger_key).await, 0);
+  assert_eq!(fixture.lamports(bidder).await, bidder_lamports + player_rent + record_rent);
+  assert_eq!(
+    fixture.lamports(creator).await,
+    creator_lamports + player_rent + record_rent + rent.minimum_balance(LEDGER_ACCOUNT_SIZE),
+  );
+}
+
+#[tokio::test]
+async fn close_ledger_returns_base_rent_once_records_are_released() {
+  let rent = Rent::default();
+  let Game { mut fixture, creator, bidder } = ended_game().await;
+  let (creator, bidder) = (creator.pubkey(), bidder.pubkey());
+  let creator_player = fixture.player_key(GAME_ID, &creator, 1);
+  let bidder_player = fixture.player_key(GAME_ID, &bidder, 2);
+  let ledger_key = fixture.ledger_key(GAME_ID);
+
+  close_accounts(&mut fixture, &[(creator_player, creator)]).await.unwrap();
+  close_accounts(&mut fixture, &[(bidder_player, bidder)]).await.unwrap();
+
+  let ledger = fixture.account(ledger_key).await.unwrap();
+  assert_eq!(ledger.data.len(), LEDGER_ACCOUNT_SIZE);
+  assert_eq!(ledger.lamports, rent.minimum_balance(LEDGER_ACCOUNT_SIZE));
+
+  assert_eq!(
+    close_ledger(&mut fixture, bidder).await.unwrap_err(),
+    custom_error(BiddingError::InvalidPayerAccount),
+  );
+
+  let creator_lamports = fixture.lamports(creator).await;
+  close_ledger(&mut fixture, creator).await.unwrap();
+
+  assert_eq!(fixture.lamports(ledger_key).await, 0);
+  assert_eq!(fixture.lamports(creator).await, creator_lamports + rent.minimum_balance(LEDGER_ACCOUNT_SIZE));
+}
+
+#[tokio::test]
+async fn close_accounts_returns_stats_rent_to_its_payer() {
+  let rent = Rent::default();
+  let Game { mut fixture, bidder, .. } = ended_game().await;
+  let bidder = bidder.pubkey();
+  let stats_key = fixture.stats_key(GAME_ID, &bidder);
+  let bidder_lamports = fixture.lamports(bidder).await;
+
+  close_accounts(&mut fixture, &[(stats_key, bidder)]).await.unwrap();
+
+  assert!(fixture.account(stats_key).await.is_none());
+  assert_eq!(fixture.lamports(bidder).await, bidder_lamports + rent.minimum_balance(STATS_ACCOUNT_SIZE));
+}
+
+#[tokio::test]
+async fn close_accounts_rejects_accounts_of_active_games() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let bidder = fixture.wallet().await;
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+  fixture.place_bid(&bidder, GAME_ID, 110_000_000, 2).await.unwrap();
+
+  let bidder = bidder.pubkey();
+  let bidder_player = fixture.player_key(GAME_ID, &bidder, 2);
+  let bidder_stats = fixture.stats_key(GAME_ID, &bidder);
+
+  assert_eq!(
+    close_accounts(&mut fixture, &[(bidder_player, bidder)]).await.unwrap_err(),
+    custom_error(BiddingError::GameNotEnded),
+  );
+  assert_eq!(
+    close_accounts(&mut fixture, &[(bidder_stats, bidder)]).await.unwrap_err(),
+    custom_error(BiddingError::GameNotEnded),
+  );
+}
@@ -0,0 +1,644 @@
+#![allow(dead_code)]
+
+use programs::error::BiddingError;
+use programs::instructions::{BiddingInstruction, ConfigParams};
+use programs::layout::{AccountLayout, HEADER_LEN};
+use programs::processor::process_instruction;
+use programs::state::{GameMode, GameRules, PriceCurve};
+use programs::utils::{
+  auto_bid_pda_seeds,
+  balance_pda_seeds,
+  config_pda_seeds,
+  game_pda_seeds,
+  ledger_pda_seeds,
+  player_pda_seeds,
+  session_pda_seeds,
+  settlement_pda_seeds,
+  stats_pda_seeds,
+  treasury_pda_seeds,
+  vault_pda_seeds,
+};
+use solana_program::{
+  bpf_loader_upgradeable,
+  clock::Clock,
+  instruction::{AccountMeta, Instruction, InstructionError},
+  pubkey,
+  pubkey::Pubkey,
+  rent::Rent,
+  system_instruction,
+  system_program,
+};
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+  account::Account,
+  signature::{Keypair, Signer},
+  transaction::{Transaction, TransactionError},
+};
+use spl_token_2022::{
+  extension::{transfer_fee, BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+  state::{Account as TokenAccount, Mint},
+};
+
+pub const WALLET_LAMPORTS: u64 = 10_000_000_000;
+pub const INITIAL_BID: u64 = 100_000_000;
+pub const SPL_TOKEN_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+pub const TOKEN_DECIMALS: u8 = 6;
+
+pub struct Fixture {
+  pub context: ProgramTestContext,
+  pub program_id: Pubkey,
+  pub authority: Keypair,
+}
+
+pub fn default_rules() -> GameRules {
+  GameRules {
+    bid_timeout: 600,
+    bid_multiplier_bps: 11_000,
+    royalty_window: 3,
+    snipe_window: 0,
+    snipe_extension: 0,
+    max_snipe_extension: 0,
+    reveal_window: 0,
+    max_bids_per_wallet: u64::MAX,
+    bid_cooldown: 0,
+    no_self_outbid: false,
+  }
+}
+
+pub fn config_params() -> ConfigParams {
+  ConfigParams {
+    platform_fee_percentage: 10,
+    min_initial_bid: 1_000_000,
+    default_rules: default_rules(),
+    min_rules: GameRules {
+      bid_timeout: 1,
+      bid_multiplier_bps: 10_001,
+      royalty_window: 3,
+      snipe_window: 0,
+      snipe_extension: 0,
+      max_snipe_extension: 0,
+      reveal_window: 0,
+      max_bids_per_wallet: 1,
+      bid_cooldown: 0,
+      no_self_outbid: false,
+    },
+    max_rules: GameRules {
+      bid_timeout: 86_400,
+      bid_multiplier_bps: 100_000,
+      royalty_window: 50,
+      snipe_window: 3_600,
+      snipe_extension: 3_600,
+      max_snipe_extension: 86_400,
+      reveal_window: 86_400,
+      max_bids_per_wallet: u64::MAX,
+      bid_cooldown: 86_400,
+      no_self_outbid: true,
+    },
+  }
+}
+
+pub fn custom_error(error: BiddingError) -> TransactionError {
+  TransactionError::InstructionError(0, InstructionError::Custom(error as u32))
+}
+
+pub fn program_account(data: Vec<u8>, program_id: &Pubkey) -> Account {
+  Account {
+    lamports: Rent::default().minimum_balance(data.len()),
+    data,
+    owner: *program_id,
+    executable: false,
+    rent_epoch: 0,
+  }
+}
+
+pub fn wallet_account() -> Account {
+  Account::new(WALLET_LAMPORTS, 0, &system_program::id())
+}
+
+pub fn token_accounts(token_program: &Pubkey, mint: &Pubkey, token_account: &Pubkey) -> Vec<AccountMeta> {
+  vec![
+    AccountMeta::new_readonly(*token_program, false),
+    AccountMeta::new_readonly(*mint, false),
+    AccountMeta::new(*token_account, false),
+  ]
+}
+
+fn program_data_account(upgrade_authority: &Pubkey) -> Account {
+  let mut data = 3u32.to_le_bytes().to_vec();
+  data.extend_from_slice(&0u64.to_le_bytes());
+  data.push(1);
+  data.extend_from_slice(upgrade_authority.as_ref());
+
+  Account {
+    lamports: Rent::default().minimum_balance(data.len()),
+    data,
+    owner: bpf_loader_upgradeable::id(),
+    executable: false,
+    rent_epoch: 0,
+  }
+}
+
+pub async fn start() -> Fixture {
+  start_with(|_, _| {}).await
+}
+
+pub async fn start_with(setup: impl FnOnce(&mut ProgramTest, &Pubkey)) -> Fixture {
+  let program_id = Pubkey::new_unique();
+  let authority = Keypair::new();
+
+  let mut program_test = ProgramTest::new("programs", program_id, processor!(process_instruction));
+  program_test.add_account(authority.pubkey(), wallet_account());
+  program_test.add_account(
+    bpf_loader_upgradeable::get_program_data_address(&program_id),
+    program_data_account(&authority.pubkey()),
+  );
+  setup(&mut program_test, &program_id);
+
+  Fixture {
+    context: program_test.start_with_context().await,
+    program_id,
+    authority,
+  }
+}
+
+pub async fn start_with_config() -> Fixture {
+  let mut fixture = start().await;
+  fixture.initialize_config(config_params()).await.unwrap();
+  fixture
+}
+
+impl Fixture {
+  pub fn game_key(&self, game_id: u64) -> Pubkey {
+    game_pda_seeds(game_id, &self.program_id).0
+  }
+
+  pub fn vault_key(&self, game_id: u64) -> Pubkey {
+    vault_pda_seeds(game_id, &self.program_id).0
+  }
+
+  pub fn ledger_key(&self, game_id: u64) -> Pubkey {
+    ledger_pda_seeds(game_id, &self.program_id).0
+  }
+
+  pub fn player_key(&self, game_id: u64, player: &Pubkey, bid_count: u64) -> Pubkey {
+    player_pda_seeds(game_id, player, bid_count, &self.program_id).0
+  }
+
+  pub fn stats_key(&self, game_id: u64, player: &Pubkey) -> Pubkey {
+    stats_pda_seeds(game_id, player, &self.program_id).0
+  }
+
+  pub fn settlement_key(&self, game_id: u64) -> Pubkey {
+    settlement_pda_seeds(game_id, &self.program_id).0
+  }
+
+  pub fn config_key(&self) -> Pubkey {
+    config_pda_seeds(&self.program_id).0
+  }
+
+  pub fn treasury_key(&self) -> Pubkey {
+    treasury_pda_seeds(&self.program_id).0
+  }
+
+  pub async fn send(&mut self, instructions: &[Instruction], signers: &[&Keypair]) -> Result<(), TransactionError> {
+    let context = &mut self.context;
+    let blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    let mut keypairs = vec![&context.payer];
+    keypairs.extend_from_slice(signers);
+
+    let transaction = Transaction::new_signed_with_payer(
+      instructions,
+      Some(&context.payer.pubkey()),
+      &keypairs,
+      blockhash,
+    );
+
+    context.banks_client
+      .process_transaction(transaction)
+      .await
+      .map_err(|err| err.unwrap())
+  }
+
+  pub async fn process(
+    &mut self,
+    instruction: &BiddingInstruction,
+    accounts: Vec<AccountMeta>,
+    signers: &[&Keypair],
+  ) -> Result<(), TransactionError> {
+    let instruction = Instruction::new_with_bytes(
+      self.program_id,
+      &borsh::to_vec(instruction).unwrap(),
+      accounts,
+    );
+
+    self.send(&[instruction], signers).await
+  }
+
+  pub async fn wallet(&mut self) -> Keypair {
+    let wallet = Keypair::new();
+    let payer = self.context.payer.pubkey();
+    let transfer = system_instruction::transfer(&payer, &wallet.pubkey(), WALLET_LAMPORTS);
+
+    self.send(&[transfer], &[]).await.unwrap();
+    wallet
+  }
+
+  pub async fn account(&mut self, key: Pubkey) -> Option<Account> {
+    self.context.banks_client.get_account(key).await.unwrap()
+  }
+
+  pub async fn lamports(&mut self, key: Pubkey) -> u64 {
+    self.account(key).await.map_or(0, |account| account.lamports)
+  }
+
+  pub async fn state<T: AccountLayout>(&mut self, key: Pubkey) -> T {
+    let account = self.account(key).await.unwrap();
+    assert_eq!(account.data[0], T::KIND as u8);
+    assert_eq!(account.data[1], T::VERSION);
+
+    T::deserialize(&mut &account.data[HEADER_LEN..]).unwrap()
+  }
+
+  pub async fn now(&mut self) -> u64 {
+    let clock = self.context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp as u64
+  }
+
+  pub async fn warp(&mut self, seconds: u64) {
+    let mut clock = self.context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp += seconds as i64;
+    self.context.set_sysvar(&clock);
+  }
+
+  pub async fn initialize_config(&mut self, params: ConfigParams) -> Result<(), TransactionError> {
+    let authority = self.authority.insecure_clone();
+    let accounts = vec![
+      AccountMeta::new(self.config_key(), false),
+      AccountMeta::new(authority.pubkey(), true),
+      AccountMeta::new_readonly(system_program::id(), false),
+      AccountMeta::new(self.treasury_key(), false),
+      AccountMeta::new_readonly(bpf_loader_upgradeable::get_program_data_address(&self.program_id), false),
+    ];
+
+    self.process(&BiddingInstruction::InitializeConfig { params }, accounts, &[&authority]).await
+  }
+
+  pub fn create_game_accounts(&self, game_id: u64, creator: &Pubkey) -> Vec<AccountMeta> {
+    vec![
+      AccountMeta::new(self.game_key(game_id), false),
+      AccountMeta::new(*creator, true),
+      AccountMeta::new_readonly(system_program::id(), false),
+      AccountMeta::new(self.player_key(game_id, creator, 1), false),
+      AccountMeta::new(self.ledger_key(game_id), false),
+      AccountMeta::new(self.stats_key(game_id, creator), false),
+      AccountMeta::new_readonly(self.config_key(), false),
+      AccountMeta::new(self.vault_key(game_id), false),
+    ]
+  }
+
+  #[allow(clippy::too_many_arguments)]
+  pub async fn create_game_with(
+    &mut self,
+    creator: &Keypair,
+    game_id: u64,
+    initial_bid_amount: u64,
+    rules: Option<GameRules>,
+    mode: GameMode,
+    price_curve: Option<PriceCurve>,
+    ends_at: Option<u64>,
+  ) -> Result<(), TransactionError> {
+    let instruction = BiddingInstruction::CreateGame {
+      game_id,
+      initial_bid_amount,
+      rules,
+      mode,
+      price_curve,
+      ends_at,
+    };
+    let accounts = self.create_game_accounts(game_id, &creator.pubkey());
+
+    self.process(&instruction, accounts, &[creator]).await
+  }
+
+  pub async fn create_game(&mut self, creator: &Keypair, game_id: u64) -> Result<(), TransactionError> {
+    self.create_game_with(creator, game_id, INITIAL_BID, None, GameMode::Open, None, None).await
+  }
+
+  pub fn place_bid_accounts(&self, game_id: u64, bidder: &Pubkey, beneficiary: &Pubkey, bid_count: u64) -> Vec<AccountMeta> {
+    vec![
+      AccountMeta::new_readonly(system_program::id(), false),
+      AccountMeta::new_readonly(system_program::id(), false),
+      AccountMeta::new(self.game_key(game_id), false),
+      AccountMeta::new(*bidder, true),
+      AccountMeta::new(self.ledger_key(game_id), false),
+      AccountMeta::new(self.player_key(game_id, beneficiary, bid_count), false),
+      AccountMeta::new(self.vault_key(game_id), false),
+      AccountMeta::new(self.stats_key(game_id, beneficiary), false),
+    ]
+  }
+
+  pub async fn place_bid(
+    &mut self,
+    bidder: &Keypair,
+    game_id: u64,
+    bid_amount: u64,
+    bid_count: u64,
+  ) -> Result<(), TransactionError> {
+    let accounts = self.place_bid_accounts(game_id, &bidder.pubkey(), &bidder.pubkey(), bid_count);
+
+    self.process(&BiddingInstruction::PlaceBid { bid_amount, bid_count }, accounts, &[bidder]).await
+  }
+
+  pub async fn sponsor_bid(
+    &mut self,
+    sponsor: &Keypair,
+    beneficiary: &Keypair,
+    game_id: u64,
+    bid_amount: u64,
+    bid_count: u64,
+  ) -> Result<(), TransactionError> {
+    let mut accounts = self.place_bid_accounts(game_id, &sponsor.pubkey(), &beneficiary.pubkey(), bid_count);
+    accounts.push(AccountMeta::new_readonly(beneficiary.pubkey(), true));
+
+    let instruction = BiddingInstruction::PlaceBid { bid_amount, bid_count };
+    self.process(&instruction, accounts, &[sponsor, beneficiary]).await
+  }
+
+  pub fn end_game_accounts(&self, game_id: u64, payer: &Pubkey) -> Vec<AccountMeta> {
+    vec![
+      AccountMeta::new_readonly(system_program::id(), false),
+      AccountMeta::new(self.treasury_key(), false),
+      AccountMeta::new(self.game_key(game_id), false),
+      AccountMeta::new(*payer, true),
+      AccountMeta::new(self.settlement_key(game_id), false),
+      AccountMeta::new(self.vault_key(game_id), false),
+    ]
+  }
+
+  pub async fn end_game(
+    &mut self,
+    payer: &Keypair,
+    game_id: u64,
+    winner: &Pubkey,
+    total_bids: u64,
+  ) -> Result<(), TransactionError> {
+    let mut accounts = self.end_game_accounts(game_id, &payer.pubkey());
+    accounts.extend([
+      AccountMeta::new_readonly(self.ledger_key(game_id), false),
+      AccountMeta::new(*winner, false),
+      AccountMeta::new(self.player_key(game_id, winner, total_bids), false),
+    ]);
+
+    self.process(&BiddingInstruction::EndGame { game_id }, accounts, &[payer]).await
+  }
+
+  pub async fn settle_batch(
+    &mut self,
+    game_id: u64,
+    start: u64,
+    count: u64,
+    extra_accounts: Vec<AccountMeta>,
+  ) -> Result<(), TransactionError> {
+    let mut accounts = vec![
+      AccountMeta::new(self.game_key(game_id), false),
+      AccountMeta::new(self.settlement_key(game_id), false),
+      AccountMeta::new(self.vault_key(game_id), false),
+      AccountMeta::new_readonly(self.ledger_key(game_id), false),
+    ];
+    accounts.extend(extra_accounts);
+
+    self.process(&BiddingInstruction::SettleBatch { start, count }, accounts, &[]).await
+  }
+
+  pub async fn claim_royalty(&mut self, bidder: &Keypair, game_id: u64, bid_count: u64) -> Result<(), TransactionError> {
+    let accounts = vec![
+      AccountMeta::new(self.game_key(game_id), false),
+      AccountMeta::new(self.player_key(game_id, &bidder.pubkey(), bid_count), false),
+      AccountMeta::new(bidder.pubkey(), true),
+      AccountMeta::new(self.vault_key(game_id), false),
+    ];
+
+    self.process(&BiddingInstruction::ClaimRoyalty { game_id, bid_count }, accounts, &[bidder]).await
+  }
+
+  pub fn auto_bid_key(&self, game_id: u64, player: &Pubkey) -> Pubkey {
+    auto_bid_pda_seeds(game_id, player, &self.program_id).0
+  }
+
+  pub async fn set_auto_bid(&mut self, player: &Keypair, game_id: u64, max_budget: u64) -> Result<(), TransactionError> {
+    let accounts = vec![
+      AccountMeta::new_readonly(system_program::id(), false),
+      AccountMeta::new(self.game_key(game_id), false),
+      AccountMeta::new(player.pubkey(), true),
+      AccountMeta::new(self.auto_bid_key(game_id, &player.pubkey()), false),
+      AccountMeta::new(self.vault_key(game_id), false),
+    ];
+
+    self.process(&BiddingInstruction::SetAutoBid { game_id, max_budget }, accounts, &[player]).await
+  }
+
+  pub async fn cancel_auto_bid(
+    &mut self,
+    player: &Pubkey,
+    game_id: u64,
+    signers: &[&Keypair],
+  ) -> Result<(), TransactionError> {
+    let signed = signers.iter().any(|signer| signer.pubkey() == *player);
+    let accounts = vec![
+      AccountMeta::new(self.game_key(game_id), false),
+      AccountMeta::new(*player, signed),
+      AccountMeta::new(self.auto_bid_key(game_id, player), false),
+      AccountMeta::new(self.vault_key(game_id), false),
+    ];
+
+    self.process(&BiddingInstruction::CancelAutoBid { game_id }, accounts, signers).await
+  }
+
+  pub async fn cancel_game(&mut self, creator: &Pubkey, game_id: u64) -> Result<(), TransactionError> {
+    let accounts = vec![
+      AccountMeta::new(self.game_key(game_id), false),
+      AccountMeta::new(*creator, false),
+      AccountMeta::new(self.player_key(game_id, creator, 1), false),
+      AccountMeta::new(self.ledger_key(game_id), false),
+      AccountMeta::new(self.stats_key(game_id, creator), false),
+      AccountMeta::new(self.vault_key(game_id), false),
+    ];
+
+    self.process(&BiddingInstruction::CancelGame { game_id }, accounts, &[]).await
+  }
+
+  pub fn balance_key(&self, owner: &Pubkey) -> Pubkey {
+    balance_pda_seeds(owner, &self.program_id).0
+  }
+
+  pub fn session_key(&self, owner: &Pubkey) -> Pubkey {
+    session_pda_seeds(owner, &self.program_id).0
+  }
+
+  pub async fn deposit(&mut self, owner: &Keypair, amount: u64) -> Result<(), TransactionError> {
+    let accounts = vec![
+      AccountMeta::new_readonly(system_program::id(), false),
+      AccountMeta::new(owner.pubkey(), true),
+      AccountMeta::new(self.balance_key(&owner.pubkey()), false),
+    ];
+
+    self.process(&BiddingInstruction::Deposit { amount }, accounts, &[owner]).await
+  }
+
+  pub async fn register_session(
+    &mut self,
+    owner: &Keypair,
+    session_key: &Pubkey,
+    spending_cap: u64,
+    expires_at: u64,
+  ) -> Result<(), TransactionError> {
+    let accounts = vec![
+      AccountMeta::new_readonly(system_program::id(), false),
+      AccountMeta::new(owner.pubkey(), true),
+      AccountMeta::new(self.session_key(&owner.pubkey()), false),
+    ];
+    let instruction = BiddingInstruction::RegisterSession {
+      session_key: *session_key,
+      spending_cap,
+      expires_at,
+    };
+
+    self.process(&instruction, accounts, &[owner]).await
+  }
+
+  pub async fn create_mint(&mut self, token_program: &Pubkey, transfer_fee_bps: Option<u16>) -> Pubkey {
+    let mint = Keypair::new();
+    let authority = self.context.payer.pubkey();
+    let extensions = match transfer_fee_bps {
+      Some(_) => vec![ExtensionType::TransferFeeConfig],
+      None => vec![],
+    };
+    let space = ExtensionType::try_calculate_account_len::<Mint>(&extensions).unwrap();
+
+    let mut instructions = vec![system_instruction::create_account(
+      &authority,
+      &mint.pubkey(),
+      Rent::default().minimum_balance(space),
+      space as u64,
+      token_program,
+    )];
+    if let Some(transfer_fee_bps) = transfer_fee_bps {
+      instructions.push(transfer_fee::instruction::initialize_transfer_fee_config(
+        token_program,
+        &mint.pubkey(),
+        Some(&authority),
+        Some(&authority),
+        transfer_fee_bps,
+        u64::MAX,
+      ).unwrap());
+    }
+    instructions.push(spl_token_2022::instruction::initialize_mint2(
+      token_program,
+      &mint.pubkey(),
+      &authority,
+      None,
+      TOKEN_DECIMALS,
+    ).unwrap());
+
+    self.send(&instructions, &[&mint]).await.unwrap();
+    mint.pubkey()
+  }
+
+  pub async fn create_token_account(&mut self, token_program: &Pubkey, mint: &Pubkey, owner: &Pubkey) -> Pubkey {
+    let account = Keypair::new();
+    let payer = self.context.payer.pubkey();
+    let mint_data = self.account(*mint).await.unwrap().data;
+    let mint_extensions = StateWithExtensions::<Mint>::unpack(&mint_data).unwrap().get_extension_types().unwrap();
+    let extensions = ExtensionType::get_required_init_account_extensions(&mint_extensions);
+    let space = ExtensionType::try_calculate_account_len::<TokenAccount>(&extensions).unwrap();
+
+    let instructions = [
+      system_instruction::create_account(
+        &payer,
+        &account.pubkey(),
+        Rent::default().minimum_balance(space),
+        space as u64,
+        token_program,
+      ),
+      spl_token_2022::instruction::initialize_account3(token_program, &account.pubkey(), mint, owner).unwrap(),
+    ];
+
+    self.send(&instructions, &[&account]).await.unwrap();
+    account.pubkey()
+  }
+
+  pub async fn mint_to(&mut self, token_program: &Pubkey, mint: &Pubkey, account: &Pubkey, amount: u64) {
+    let authority = self.context.payer.pubkey();
+    let instruction = spl_token_2022::instruction::mint_to(token_program, mint, account, &authority, &[], amount).unwrap();
+
+    self.send(&[instruction], &[]).await.unwrap();
+  }
+
+  pub async fn token_balance(&mut self, key: Pubkey) -> u64 {
+    let data = self.account(key).await.unwrap().data;
+    StateWithExtensions::<TokenAccount>::unpack(&data).unwrap().base.amount
+  }
+
+  pub async fn create_token_game(
+    &mut self,
+    creator: &Keypair,
+    game_id: u64,
+    token_program: &Pubkey,
+    mint: &Pubkey,
+    source: &Pubkey,
+  ) -> Result<(), TransactionError> {
+    let mut accounts = self.create_game_accounts(game_id, &creator.pubkey());
+    accounts.extend(token_accounts(token_program, mint, source));
+    let instruction = BiddingInstruction::CreateGame {
+      game_id,
+      initial_bid_amount: INITIAL_BID,
+      rules: None,
+      mode: GameMode::Open,
+      price_curve: None,
+      ends_at: None,
+    };
+
+    self.process(&instruction, accounts, &[creator]).await
+  }
+
+  #[allow(clippy::too_many_arguments)]
+  pub async fn place_token_bid(
+    &mut self,
+    bidder: &Keypair,
+    game_id: u64,
+    bid_amount: u64,
+    bid_count: u64,
+    token_program: &Pubkey,
+    mint: &Pubkey,
+    source: &Pubkey,
+  ) -> Result<(), TransactionError> {
+    let mut accounts = self.place_bid_accounts(game_id, &bidder.pubkey(), &bidder.pubkey(), bid_count);
+    accounts.extend(token_accounts(token_program, mint, source));
+
+    self.process(&BiddingInstruction::PlaceBid { bid_amount, bid_count }, accounts, &[bidder]).await
+  }
+
+  #[allow(clippy::too_many_arguments)]
+  pub async fn end_token_game(
+    &mut self,
+    payer: &Keypair,
+    game_id: u64,
+    token_program: &Pubkey,
+    mint: &Pubkey,
+    treasury_tokens: &Pubkey,
+    winner: &Pubkey,
+    winner_tokens: &Pubkey,
+    total_bids: u64,
+  ) -> Result<(), TransactionError> {
+    let mut accounts = self.end_game_accounts(game_id, &payer.pubkey());
+    accounts.extend(token_accounts(token_program, mint, treasury_tokens));
+    accounts.extend([
+      AccountMeta::new(*winner_tokens, false),
+      AccountMeta::new_readonly(self.ledger_key(game_id), false),
+      AccountMeta::new(self.player_key(game_id, winner, total_bids), false),
+    ]);
+
+    self.process(&BiddingInstruction::EndGame { game_id }, accounts, &[payer]).await
+  }
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use common::{config_params, custom_error, start, start_with_config, Fixture};
+use programs::error::BiddingError;
+use programs::instructions::{BiddingInstruction, ConfigParams};
+use programs::state::{GameState, PlatformConfig};
+use solana_program::{bpf_loader_upgradeable, instruction::AccountMeta, pubkey::Pubkey, system_program};
+use solana_sdk::{
+  signature::{Keypair, Signer},
+  transaction::TransactionError,
+};
+
+const GAME_ID: u64 = 6;
+
+async fn update_config(
+  fixture: &mut Fixture,
+  authority: &Keypair,
+  params: ConfigParams,
+  new_authority: Option<Pubkey>,
+) -> Result<(), TransactionError> {
+  let accounts = vec![
+    AccountMeta::new(fixture.config_key(), false),
+    AccountMeta::new_readonly(authority.pubkey(), true),
+  ];
+
+  fixture.process(&BiddingInstruction::UpdateConfig { params, new_authority }, accounts, &[authority]).await
+}
+
+#[tokio::test]
+async fn update_config_applies_fee_to_new_games_and_hands_over_authority() {
+  let mut fixture = start_with_config().await;
+  let authority = fixture.authority.insecure_clone();
+  let successor = fixture.wallet().await;
+  let creator = fixture.wallet().await;
+
+  let params = ConfigParams { platform_fee_percentage: 20, ..config_params() };
+  update_config(&mut fixture, &authority, params, Some(successor.pubkey())).await.unwrap();
+
+  let config = fixture.state::<PlatformConfig>(fixture.config_key()).await;
+  assert_eq!(config.authority, successor.pubkey());
+  assert_eq!(config.platform_fee_percentage, 20);
+
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+  let game_state = fixture.state::<GameState>(fixture.game_key(GAME_ID)).await;
+  assert_eq!(game_state.platform_fee_percentage, 20);
+
+  assert_eq!(
+    update_config(&mut fixture, &authority, config_params(), None).await.unwrap_err(),
+    custom_error(BiddingError::InvalidAuthority),
+  );
+}
+
+#[tokio::test]
+async fn update_config_rejects_invalid_params() {
+  let mut fixture = start_with_config().await;
+  let authority = fixture.authority.insecure_clone();
+
+  let mut params = config_params();
+  params.min_rules.bid_timeout = params.max_rules.bid_timeout + 1;
+  assert_eq!(
+    update_config(&mut fixture, &authority, params, None).await.unwrap_err(),
+    custom_error(BiddingError::InvalidConfig),
+  );
+}
+
+#[tokio::test]
+async fn initialize_config_requires_upgrade_authority() {
+  let mut fixture = start().await;
+  let impostor = fixture.wallet().await;
+  let accounts = vec![
+    AccountMeta::new(fixture.config_key(), false),
+    AccountMeta::new(impostor.pubkey(), true),
+    AccountMeta::new_readonly(system_program::id(), false),
+    AccountMeta::new(fixture.treasury_key(), false),
+    AccountMeta::new_readonly(bpf_loader_upgradeable::get_program_data_address(&fixture.program_id), false),
+  ];
+  let instruction = BiddingInstruction::InitializeConfig { params: config_params() };
+
+  assert_eq!(
+    fixture.process(&instruction, accounts, &[&impostor]).await.unwrap_err(),
+    custom_error(BiddingError::InvalidAuthority),
+  );
+  assert!(fixture.account(fixture.config_key()).await.is_none());
+}
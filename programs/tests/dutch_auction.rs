@@ -0,0 +1,94 @@
+mod common;
+
+use common::{custom_error, start_with_config, Fixture, INITIAL_BID};
+use programs::error::BiddingError;
+use programs::state::{CurveKind, GameMode, GameState, GameStatus, PriceCurve};
+use solana_sdk::signature::{Keypair, Signer};
+
+const GAME_ID: u64 = 14;
+const START_PRICE: u64 = 300_000_000;
+const FLOOR_PRICE: u64 = 100_000_000;
+
+fn linear_curve() -> PriceCurve {
+  PriceCurve {
+    kind: CurveKind::Linear,
+    start_price: START_PRICE,
+    floor_price: FLOOR_PRICE,
+    step_interval: 0,
+  }
+}
+
+async fn dutch_game() -> (Fixture, Keypair, u64) {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+
+  fixture
+    .create_game_with(&creator, GAME_ID, INITIAL_BID, None, GameMode::Dutch, Some(linear_curve()), None)
+    .await
+    .unwrap();
+  let started_at = fixture.now().await;
+
+  (fixture, creator, started_at)
+}
+
+#[tokio::test]
+async fn bid_at_the_decayed_price_closes_and_wins() {
+  let (mut fixture, creator, started_at) = dutch_game().await;
+  let bidder = fixture.wallet().await;
+
+  fixture.warp(300).await;
+  let price = START_PRICE - (START_PRICE - FLOOR_PRICE) * (fixture.now().await - started_at) / 600;
+  fixture.place_bid(&bidder, GAME_ID, price, 2).await.unwrap();
+
+  let now = fixture.now().await;
+  let game_state = fixture.state::<GameState>(fixture.game_key(GAME_ID)).await;
+  assert_eq!(game_state.highest_bid, price);
+  assert_eq!(game_state.deadline, now);
+
+  fixture.warp(1).await;
+  let winner_lamports = fixture.lamports(bidder.pubkey()).await;
+  fixture.end_game(&creator, GAME_ID, &bidder.pubkey(), 2).await.unwrap();
+
+  let pool = INITIAL_BID + price;
+  assert_eq!(fixture.lamports(bidder.pubkey()).await, winner_lamports + pool - pool / 10);
+
+  let game_state = fixture.state::<GameState>(fixture.game_key(GAME_ID)).await;
+  assert_eq!(game_state.status, GameStatus::Ended);
+}
+
+#[tokio::test]
+async fn dutch_game_rejects_underpriced_and_second_bids() {
+  let (mut fixture, _, started_at) = dutch_game().await;
+  let bidder = fixture.wallet().await;
+  let late_bidder = fixture.wallet().await;
+
+  fixture.warp(300).await;
+  let price = START_PRICE - (START_PRICE - FLOOR_PRICE) * (fixture.now().await - started_at) / 600;
+  assert_eq!(
+    fixture.place_bid(&bidder, GAME_ID, price - 1, 2).await.unwrap_err(),
+    custom_error(BiddingError::InsufficientBidAmount),
+  );
+
+  fixture.place_bid(&bidder, GAME_ID, price, 2).await.unwrap();
+  assert_eq!(
+    fixture.place_bid(&late_bidder, GAME_ID, START_PRICE, 3).await.unwrap_err(),
+    custom_error(BiddingError::BiddingClosed),
+  );
+}
+
+#[tokio::test]
+async fn dutch_game_requires_a_valid_price_curve() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let inverted = PriceCurve { start_price: FLOOR_PRICE, floor_price: START_PRICE, ..linear_curve() };
+
+  for curve in [None, Some(inverted)] {
+    assert_eq!(
+      fixture
+        .create_game_with(&creator, GAME_ID, INITIAL_BID, None, GameMode::Dutch, curve, None)
+        .await
+        .unwrap_err(),
+      custom_error(BiddingError::InvalidPriceCurve),
+    );
+  }
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use common::{custom_error, start_with_config, INITIAL_BID};
+use programs::error::BiddingError;
+use programs::state::{GameState, GameStatus, PlayerState};
+use solana_sdk::signature::Signer;
+
+const GAME_ID: u64 = 1;
+const BID: u64 = 110_000_000;
+
+#[tokio::test]
+async fn anyone_can_end_game_and_pay_winner() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let bidder = fixture.wallet().await;
+  let keeper = fixture.wallet().await;
+  let winner = bidder.pubkey();
+
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+  fixture.place_bid(&bidder, GAME_ID, BID, 2).await.unwrap();
+  fixture.warp(601).await;
+
+  let fee = (INITIAL_BID + BID) / 10;
+  let winner_lamports = fixture.lamports(winner).await;
+  let treasury_lamports = fixture.lamports(fixture.treasury_key()).await;
+
+  fixture.end_game(&keeper, GAME_ID, &winner, 2).await.unwrap();
+
+  assert_eq!(fixture.lamports(winner).await, winner_lamports + INITIAL_BID + BID - fee);
+  assert_eq!(fixture.lamports(fixture.treasury_key()).await, treasury_lamports + fee);
+
+  let game_state = fixture.state::<GameState>(fixture.game_key(GAME_ID)).await;
+  assert_eq!(game_state.status, GameStatus::Ended);
+
+  let player = fixture.state::<PlayerState>(fixture.player_key(GAME_ID, &winner, 2)).await;
+  assert!(player.safe && player.claimed);
+
+  assert_eq!(
+    fixture.end_game(&keeper, GAME_ID, &winner, 2).await.unwrap_err(),
+    custom_error(BiddingError::GameEnded),
+  );
+}
+
+#[tokio::test]
+async fn end_game_rejects_wrong_winner() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let bidder = fixture.wallet().await;
+
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+  fixture.place_bid(&bidder, GAME_ID, BID, 2).await.unwrap();
+  fixture.warp(601).await;
+
+  assert_eq!(
+    fixture.end_game(&creator, GAME_ID, &creator.pubkey(), 1).await.unwrap_err(),
+    custom_error(BiddingError::NoWinnerFound),
+  );
+}
+
+#[tokio::test]
+async fn end_game_rejects_uncontested_game() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+  fixture.warp(601).await;
+
+  assert_eq!(
+    fixture.end_game(&creator, GAME_ID, &creator.pubkey(), 1).await.unwrap_err(),
+    custom_error(BiddingError::UncontestedGame),
+  );
+}
@@ -0,0 +1,56 @@
+mod common;
+
+use common::{custom_error, start_with_config, INITIAL_BID};
+use programs::error::BiddingError;
+use programs::state::{GameMode, GameState, GameStatus};
+use solana_sdk::signature::Signer;
+
+const GAME_ID: u64 = 19;
+const BIDS: [u64; 2] = [110_000_000, 121_000_000];
+
+#[tokio::test]
+async fn deadline_never_passes_the_hard_end_time() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let bidder = fixture.wallet().await;
+  let late_bidder = fixture.wallet().await;
+  let ends_at = fixture.now().await + 900;
+
+  fixture
+    .create_game_with(&creator, GAME_ID, INITIAL_BID, None, GameMode::Open, None, Some(ends_at))
+    .await
+    .unwrap();
+
+  fixture.warp(500).await;
+  fixture.place_bid(&bidder, GAME_ID, BIDS[0], 2).await.unwrap();
+
+  let game_state = fixture.state::<GameState>(fixture.game_key(GAME_ID)).await;
+  assert_eq!(game_state.ends_at, ends_at);
+  assert_eq!(game_state.deadline, ends_at);
+
+  fixture.warp(401).await;
+  assert_eq!(
+    fixture.place_bid(&late_bidder, GAME_ID, BIDS[1], 3).await.unwrap_err(),
+    custom_error(BiddingError::BiddingClosed),
+  );
+
+  fixture.end_game(&creator, GAME_ID, &bidder.pubkey(), 2).await.unwrap();
+
+  let game_state = fixture.state::<GameState>(fixture.game_key(GAME_ID)).await;
+  assert_eq!(game_state.status, GameStatus::Ended);
+}
+
+#[tokio::test]
+async fn create_game_rejects_end_times_not_in_the_future() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let now = fixture.now().await;
+
+  assert_eq!(
+    fixture
+      .create_game_with(&creator, GAME_ID, INITIAL_BID, None, GameMode::Open, None, Some(now))
+      .await
+      .unwrap_err(),
+    custom_error(BiddingError::InvalidEndTime),
+  );
+}
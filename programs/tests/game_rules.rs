@@ -0,0 +1,67 @@
+mod common;
+
+use common::{custom_error, default_rules, start_with_config, INITIAL_BID};
+use programs::error::BiddingError;
+use programs::state::{GameMode, GameRules, GameState};
+use solana_sdk::signature::Signer;
+
+const GAME_ID: u64 = 7;
+
+fn custom_rules() -> GameRules {
+  GameRules {
+    bid_timeout: 60,
+    bid_multiplier_bps: 20_000,
+    royalty_window: 4,
+    ..default_rules()
+  }
+}
+
+#[tokio::test]
+async fn create_game_applies_custom_rules() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let bidder = fixture.wallet().await;
+
+  fixture
+    .create_game_with(&creator, GAME_ID, INITIAL_BID, Some(custom_rules()), GameMode::Open, None, None)
+    .await
+    .unwrap();
+
+  let game_state = fixture.state::<GameState>(fixture.game_key(GAME_ID)).await;
+  assert_eq!(game_state.rules.bid_multiplier_bps, 20_000);
+  assert_eq!(game_state.rules.royalty_window, 4);
+  assert_eq!(game_state.deadline, game_state.started_at + 60);
+
+  assert_eq!(
+    fixture.place_bid(&bidder, GAME_ID, 2 * INITIAL_BID - 1, 2).await.unwrap_err(),
+    custom_error(BiddingError::InsufficientBidAmount),
+  );
+  fixture.place_bid(&bidder, GAME_ID, 2 * INITIAL_BID, 2).await.unwrap();
+
+  fixture.warp(61).await;
+  fixture.end_game(&creator, GAME_ID, &bidder.pubkey(), 2).await.unwrap();
+}
+
+#[tokio::test]
+async fn create_game_rejects_rules_outside_config_bounds() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+
+  let rules = GameRules { bid_timeout: 86_401, ..custom_rules() };
+  assert_eq!(
+    fixture
+      .create_game_with(&creator, GAME_ID, INITIAL_BID, Some(rules), GameMode::Open, None, None)
+      .await
+      .unwrap_err(),
+    custom_error(BiddingError::InvalidGameRules),
+  );
+
+  let rules = GameRules { royalty_window: 2, ..custom_rules() };
+  assert_eq!(
+    fixture
+      .create_game_with(&creator, GAME_ID, INITIAL_BID, Some(rules), GameMode::Open, None, None)
+      .await
+      .unwrap_err(),
+    custom_error(BiddingError::InvalidGameRules),
+  );
+}
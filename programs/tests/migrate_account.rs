@@ -0,0 +1,374 @@
+mod common;
+
+use borsh::BorshDeserialize;
+use common::{
+  config_params,
+  custom_error,
+  program_account,
+  start_with,
+  start_with_config,
+  wallet_account,
+  Fixture,
+  WALLET_LAMPORTS,
+};
+use programs::error::BiddingError;
+use programs::instructions::BiddingInstruction;
+use programs::layout::AccountLayout;
+use programs::state::{
+  AccountKind,
+  Bid,
+  BidV1,
+  GameMode,
+  GameState,
+  GameStateV1,
+  GameStatus,
+  Ledger,
+  PlayerState,
+  PlayerStateV1,
+  PlayerStats,
+  BID_RECORD_SIZE,
+  BID_V1_ACCOUNT_SIZE,
+  GAME_ACCOUNT_SIZE,
+  GAME_V1_ACCOUNT_SIZE,
+  LEDGER_ACCOUNT_SIZE,
+  PLAYER_ACCOUNT_SIZE,
+  PLAYER_V1_ACCOUNT_SIZE,
+  STATS_ACCOUNT_SIZE,
+};
+use programs::utils::{bid_pda_seeds, game_pda_seeds, player_pda_seeds};
+use solana_program::{
+  instruction::AccountMeta,
+  pubkey::Pubkey,
+  rent::Rent,
+  system_program,
+};
+use solana_sdk::{
+  account::Account,
+  signature::{Keypair, Signer},
+  transaction::TransactionError,
+};
+
+const ACTIVE_V1: &[u8] = include_bytes!("fixtures/game_state_v1_active.bin");
+const ENDED_V1: &[u8] = include_bytes!("fixtures/game_state_v1_ended.bin");
+const BIDS_V1: [&[u8]; 3] = [
+  include_bytes!("fixtures/bid_v1_1.bin"),
+  include_bytes!("fixtures/bid_v1_2.bin"),
+  include_bytes!("fixtures/bid_v1_3.bin"),
+];
+const PLAYERS_V1: [&[u8]; 3] = [
+  include_bytes!("fixtures/player_state_v1_1.bin"),
+  include_bytes!("fixtures/player_state_v1_2.bin"),
+  include_bytes!("fixtures/player_state_v1_3.bin"),
+];
+
+struct Game {
+  fixture: Fixture,
+  game: GameStateV1,
+  bids: Vec<BidV1>,
+}
+
+fn decode<T: BorshDeserialize>(data: &[u8]) -> T {
+  T::deserialize(&mut &data[..]).unwrap()
+}
+
+async fn start(data: &'static [u8], bid_data: &[&'static [u8]]) -> Game {
+  let game: GameStateV1 = decode(data);
+  let bids: Vec<BidV1> = bid_data.iter().map(|data| decode(data)).collect();
+  let rent = Rent::default();
+
+  let fixture = start_with(|program_test, program_id| {
+    let (game_key, _) = game_pda_seeds(game.game_id, program_id);
+    let lamports = rent.minimum_balance(GAME_V1_ACCOUNT_SIZE) + if game.game_ended { 0 } else { game.prize_pool };
+    program_test.add_account(game_key, Account { lamports, ..program_account(data.to_vec(), program_id) });
+
+    for (index, bid) in bids.iter().enumerate() {
+      let bid_number = index as u64 + 1;
+      let (bid_key, _) = bid_pda_seeds(game.game_id, bid_number, program_id);
+      let (player_key, _) = player_pda_seeds(game.game_id, &bid.bidder, bid_number, program_id);
+
+      program_test.add_account(bid_key, program_account(bid_data[index].to_vec(), program_id));
+      program_test.add_account(player_key, program_account(PLAYERS_V1[index].to_vec(), program_id));
+      program_test.add_account(bid.bidder, wallet_account());
+    }
+  }).await;
+
+  Game { fixture, game, bids }
+}
+
+fn backfill_accounts(game: &Game, bids: std::ops::Range<usize>) -> Vec<AccountMeta> {
+  let program_id = game.fixture.program_id;
+
+  bids.flat_map(|index| {
+    let bid = &game.bids[index];
+    let bid_number = index as u64 + 1;
+    let (bid_key, _) = bid_pda_seeds(game.game.game_id, bid_number, &program_id);
+    let (player_key, _) = player_pda_seeds(game.game.game_id, &bid.bidder, bid_number, &program_id);
+
+    [
+      AccountMeta::new(bid_key, false),
+      AccountMeta::new(player_key, false),
+      AccountMeta::new(bid.bidder, false),
+    ]
+  }).collect()
+}
+
+async fn migrate_game(game: &mut Game, backfill: Vec<AccountMeta>) -> Result<(), TransactionError> {
+  let game_id = game.game.game_id;
+  let fixture = &mut game.fixture;
+  let mut accounts = vec![
+    AccountMeta::new(fixture.vault_key(game_id), false),
+    AccountMeta::new(fixture.ledger_key(game_id), false),
+  ];
+  accounts.extend(backfill);
+
+  let key = fixture.game_key(game_id);
+  migrate_account(fixture, key, accounts).await
+}
+
+async fn migrate_account(
+  fixture: &mut Fixture,
+  key: Pubkey,
+  accounts: Vec<AccountMeta>,
+) -> Result<(), TransactionError> {
+  let mut metas = vec![
+    AccountMeta::new_readonly(system_program::id(), false),
+    AccountMeta::new(fixture.context.payer.pubkey(), true),
+    AccountMeta::new(key, false),
+  ];
+  metas.extend(accounts);
+
+  fixture.process(&BiddingInstruction::MigrateAccount, metas, &[]).await
+}
+
+#[test]
+fn fixtures_use_the_untagged_v1_layouts() {
+  assert_eq!(ACTIVE_V1.len(), GAME_V1_ACCOUNT_SIZE);
+  assert_eq!(ENDED_V1.len(), GAME_V1_ACCOUNT_SIZE);
+  assert!(BIDS_V1.iter().all(|data| data.len() == BID_V1_ACCOUNT_SIZE));
+  assert!(PLAYERS_V1.iter().all(|data| data.len() == PLAYER_V1_ACCOUNT_SIZE));
+
+  let game: GameStateV1 = decode(ACTIVE_V1);
+  assert_eq!(ACTIVE_V1[0], 42);
+  assert_eq!((game.game_id, game.total_bids, game.highest_bid), (42, 3, 400_000_000));
+}
+
+#[tokio::test]
+async fn migrate_account_moves_v1_game_funds_into_vault() {
+  let rent = Rent::default();
+  let mut game = start(ACTIVE_V1, &BIDS_V1).await;
+  let game_id = game.game.game_id;
+
+  migrate_game(&mut game, vec![]).await.unwrap();
+
+  let fixture = &mut game.fixture;
+  let game_key = fixture.game_key(game_id);
+  let account = fixture.account(game_key).await.unwrap();
+  assert_eq!(account.data.len(), GAME_ACCOUNT_SIZE);
+  assert_eq!(account.lamports, rent.minimum_balance(GAME_ACCOUNT_SIZE));
+  assert_eq!(account.data[0], AccountKind::Game as u8);
+  assert_eq!(account.data[1], GameState::VERSION);
+
+  let game_state = fixture.state::<GameState>(game_key).await;
+  assert_eq!(game_state.game_id, 42);
+  assert_eq!((game_state.highest_bid, game_state.total_bids), (400_000_000, 3));
+  assert_eq!(game_state.prize_pool, 700_000_000);
+  assert_eq!(game_state.last_bidder, game.bids[2].bidder);
+  assert_eq!(game_state.status, GameStatus::Active);
+  assert_eq!(game_state.mode, GameMode::Open);
+  assert_eq!(game_state.mint, Pubkey::default());
+  assert_eq!(game_state.deadline, game.game.last_bid_time + 600);
+  assert_eq!(
+    (game_state.rules.bid_timeout, game_state.rules.bid_multiplier_bps, game_state.rules.royalty_window),
+    (600, 20_000, 5),
+  );
+
+  let vault_key = fixture.vault_key(game_id);
+  assert_eq!(fixture.lamports(vault_key).await, rent.minimum_balance(0) + 700_000_000);
+
+  let ledger_key = fixture.ledger_key(game_id);
+  let ledger = fixture.state::<Ledger>(ledger_key).await;
+  assert_eq!((ledger.len, ledger.payer), (0, fixture.context.payer.pubkey()));
+}
+
+#[tokio::test]
+async fn migrate_account_backfills_ledger_and_player_accounts_from_v1_bids() {
+  let rent = Rent::default();
+  let record_rent = rent.minimum_balance(BID_RECORD_SIZE) - rent.minimum_balance(0);
+  let player_top_up = rent.minimum_balance(PLAYER_ACCOUNT_SIZE) - rent.minimum_balance(PLAYER_V1_ACCOUNT_SIZE);
+  let mut game = start(ACTIVE_V1, &BIDS_V1).await;
+  let game_id = game.game.game_id;
+
+  let accounts = backfill_accounts(&game, 0..1);
+  migrate_game(&mut game, accounts).await.unwrap();
+  let accounts = backfill_accounts(&game, 1..3);
+  migrate_game(&mut game, accounts).await.unwrap();
+
+  let ledger_key = game.fixture.ledger_key(game_id);
+  let ledger = game.fixture.account(ledger_key).await.unwrap();
+  assert_eq!(ledger.data.len(), LEDGER_ACCOUNT_SIZE + BIDS_V1.len() * BID_RECORD_SIZE);
+  assert_eq!(ledger.lamports, rent.minimum_balance(ledger.data.len()));
+  assert_eq!(Ledger::try_from_slice(&ledger.data[2..LEDGER_ACCOUNT_SIZE]).unwrap().len, 3);
+
+  let program_id = game.fixture.program_id;
+  for (index, bid) in game.bids.iter().enumerate() {
+    let bid_number = index as u64 + 1;
+    let start = LEDGER_ACCOUNT_SIZE + index * BID_RECORD_SIZE;
+    let record = Bid::try_from_slice(&ledger.data[start..start + BID_RECORD_SIZE]).unwrap();
+    assert_eq!(
+      (record.bidder, record.amount, record.timestamp, record.payer),
+      (bid.bidder, bid.amount, bid.timestamp, bid.bidder),
+    );
+
+    let (bid_key, _) = bid_pda_seeds(game_id, bid_number, &program_id);
+    assert!(game.fixture.account(bid_key).await.is_none());
+    assert_eq!(
+      game.fixture.lamports(bid.bidder).await,
+      WALLET_LAMPORTS + rent.minimum_balance(BID_V1_ACCOUNT_SIZE) - record_rent - player_top_up,
+    );
+
+    let player_key = game.fixture.player_key(game_id, &bid.bidder, bid_number);
+    let player_v1: PlayerStateV1 = decode(PLAYERS_V1[index]);
+    let player = game.fixture.state::<PlayerState>(player_key).await;
+    assert_eq!(game.fixture.lamports(player_key).await, rent.minimum_balance(PLAYER_ACCOUNT_SIZE));
+    assert_eq!((player.total_bid_amount, player.bid_count), (player_v1.total_bid_amount, bid_number));
+    assert_eq!((player.bidder, player.payer), (bid.bidder, bid.bidder));
+    assert_eq!(player.claimed, player_v1.safe);
+  }
+
+  assert_eq!(
+    migrate_game(&mut game, vec![]).await.unwrap_err(),
+    custom_error(BiddingError::AccountAlreadyMigrated),
+  );
+}
+
+#[tokio::test]
+async fn migrate_account_rejects_bid_accounts_out_of_order() {
+  let mut game = start(ACTIVE_V1, &BIDS_V1).await;
+
+  let accounts = backfill_accounts(&game, 1..2);
+  assert_eq!(
+    migrate_game(&mut game, accounts).await.unwrap_err(),
+    custom_error(BiddingError::InvalidBidAccount),
+  );
+}
+
+#[tokio::test]
+async fn migrate_account_rejects_refund_to_another_wallet() {
+  let mut game = start(ACTIVE_V1, &BIDS_V1).await;
+
+  let mut accounts = backfill_accounts(&game, 0..1);
+  accounts[2] = AccountMeta::new(game.bids[1].bidder, false);
+  assert_eq!(
+    migrate_game(&mut game, accounts).await.unwrap_err(),
+    custom_error(BiddingError::InvalidPayerAccount),
+  );
+}
+
+#[tokio::test]
+async fn migrate_account_marks_ended_v1_game_ended() {
+  let mut game = start(ENDED_V1, &[]).await;
+  let game_id = game.game.game_id;
+
+  migrate_game(&mut game, vec![]).await.unwrap();
+
+  let game_key = game.fixture.game_key(game_id);
+  let game_state = game.fixture.state::<GameState>(game_key).await;
+  assert_eq!(game_state.game_id, 43);
+  assert_eq!(game_state.status, GameStatus::Ended);
+}
+
+#[tokio::test]
+async fn migrated_v1_game_ends_and_pays_winner_from_vault() {
+  let mut game = start(ACTIVE_V1, &BIDS_V1).await;
+  let game_id = game.game.game_id;
+  let winner = game.bids[2].bidder;
+
+  let accounts = backfill_accounts(&game, 0..3);
+  migrate_game(&mut game, accounts).await.unwrap();
+
+  let fixture = &mut game.fixture;
+  fixture.initialize_config(config_params()).await.unwrap();
+  let payer = fixture.wallet().await;
+  let winner_lamports = fixture.lamports(winner).await;
+  let treasury_lamports = fixture.lamports(fixture.treasury_key()).await;
+
+  fixture.end_game(&payer, game_id, &winner, 3).await.unwrap();
+
+  let game_state = fixture.state::<GameState>(fixture.game_key(game_id)).await;
+  assert_eq!(game_state.status, GameStatus::Ended);
+  assert_eq!(fixture.lamports(winner).await, winner_lamports + 630_000_000);
+  assert_eq!(fixture.lamports(fixture.treasury_key()).await, treasury_lamports + 70_000_000);
+}
+
+async fn downgrade_stats(fixture: &mut Fixture, stats_key: Pubkey) {
+  let stats = fixture.state::<PlayerStats>(stats_key).await;
+  let mut data = vec![AccountKind::Stats as u8, 1];
+  data.extend_from_slice(&stats.game_id.to_le_bytes());
+  data.extend_from_slice(stats.player.as_ref());
+  data.extend_from_slice(&stats.bid_count.to_le_bytes());
+  data.extend_from_slice(&stats.last_bid_time.to_le_bytes());
+
+  let account = program_account(data, &fixture.program_id);
+  fixture.context.set_account(&stats_key, &account.into());
+}
+
+async fn migrate_stats(fixture: &mut Fixture, stats_key: Pubkey, game_id: u64) -> Result<(), TransactionError> {
+  let ledger = AccountMeta::new_readonly(fixture.ledger_key(game_id), false);
+  migrate_account(fixture, stats_key, vec![ledger]).await
+}
+
+#[tokio::test]
+async fn migrate_account_takes_v1_stats_payer_from_ledger() {
+  let game_id = 7;
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let sponsor = fixture.wallet().await;
+  let beneficiary = Keypair::new();
+  let player = beneficiary.pubkey();
+
+  fixture.create_game(&creator, game_id).await.unwrap();
+  fixture.sponsor_bid(&sponsor, &beneficiary, game_id, 110_000_000, 2).await.unwrap();
+
+  let stats_key = fixture.stats_key(game_id, &player);
+  let bid_time = fixture.state::<PlayerStats>(stats_key).await.last_bid_time;
+  downgrade_stats(&mut fixture, stats_key).await;
+
+  migrate_stats(&mut fixture, stats_key, game_id).await.unwrap();
+
+  let account = fixture.account(stats_key).await.unwrap();
+  assert_eq!(account.data.len(), STATS_ACCOUNT_SIZE);
+  assert_eq!(account.lamports, Rent::default().minimum_balance(STATS_ACCOUNT_SIZE));
+
+  let stats = fixture.state::<PlayerStats>(stats_key).await;
+  assert_eq!((stats.game_id, stats.player), (game_id, player));
+  assert_eq!((stats.bid_count, stats.last_bid_time), (1, bid_time));
+  assert_eq!(stats.payer, sponsor.pubkey());
+
+  assert_eq!(
+    migrate_stats(&mut fixture, stats_key, game_id).await.unwrap_err(),
+    custom_error(BiddingError::AccountAlreadyMigrated),
+  );
+}
+
+#[tokio::test]
+async fn migrate_account_refuses_v1_stats_without_a_recorded_bid() {
+  let game_id = 7;
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let player = Pubkey::new_unique();
+  fixture.create_game(&creator, game_id).await.unwrap();
+
+  let mut data = vec![AccountKind::Stats as u8, 1];
+  data.extend_from_slice(&game_id.to_le_bytes());
+  data.extend_from_slice(player.as_ref());
+  data.extend_from_slice(&1u64.to_le_bytes());
+  data.extend_from_slice(&1_750_000_000u64.to_le_bytes());
+  let stats_key = fixture.stats_key(game_id, &player);
+  let account = program_account(data, &fixture.program_id);
+  fixture.context.set_account(&stats_key, &account.into());
+
+  assert_eq!(
+    migrate_stats(&mut fixture, stats_key, game_id).await.unwrap_err(),
+    custom_error(BiddingError::BidNotRecorded),
+  );
+}
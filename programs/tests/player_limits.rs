@@ -0,0 +1,73 @@
+mod common;
+
+use common::{custom_error, default_rules, start_with_config, Fixture, INITIAL_BID};
+use programs::error::BiddingError;
+use programs::state::{GameMode, GameRules, GameState, PlayerStats};
+use solana_sdk::signature::{Keypair, Signer};
+
+const GAME_ID: u64 = 20;
+const BIDS: [u64; 5] = [110_000_000, 121_000_000, 134_000_000, 148_000_000, 163_000_000];
+
+async fn limited_game() -> (Fixture, Keypair, Keypair) {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let alice = fixture.wallet().await;
+  let bob = fixture.wallet().await;
+  let rules = GameRules {
+    max_bids_per_wallet: 2,
+    bid_cooldown: 120,
+    no_self_outbid: true,
+    ..default_rules()
+  };
+
+  fixture
+    .create_game_with(&creator, GAME_ID, INITIAL_BID, Some(rules), GameMode::Open, None, None)
+    .await
+    .unwrap();
+
+  (fixture, alice, bob)
+}
+
+#[tokio::test]
+async fn alternating_bids_after_cooldown_are_counted() {
+  let (mut fixture, alice, bob) = limited_game().await;
+
+  fixture.place_bid(&alice, GAME_ID, BIDS[0], 2).await.unwrap();
+  fixture.place_bid(&bob, GAME_ID, BIDS[1], 3).await.unwrap();
+  fixture.warp(120).await;
+  fixture.place_bid(&alice, GAME_ID, BIDS[2], 4).await.unwrap();
+
+  let now = fixture.now().await;
+  let stats = fixture.state::<PlayerStats>(fixture.stats_key(GAME_ID, &alice.pubkey())).await;
+  assert_eq!(stats.bid_count, 2);
+  assert_eq!(stats.last_bid_time, now);
+
+  let game_state = fixture.state::<GameState>(fixture.game_key(GAME_ID)).await;
+  assert_eq!(game_state.last_bidder, alice.pubkey());
+  assert_eq!(game_state.total_bids, 4);
+}
+
+#[tokio::test]
+async fn bids_breaking_wallet_rules_are_rejected() {
+  let (mut fixture, alice, bob) = limited_game().await;
+
+  fixture.place_bid(&alice, GAME_ID, BIDS[0], 2).await.unwrap();
+  assert_eq!(
+    fixture.place_bid(&alice, GAME_ID, BIDS[1], 3).await.unwrap_err(),
+    custom_error(BiddingError::SelfOutbid),
+  );
+
+  fixture.place_bid(&bob, GAME_ID, BIDS[1], 3).await.unwrap();
+  assert_eq!(
+    fixture.place_bid(&alice, GAME_ID, BIDS[2], 4).await.unwrap_err(),
+    custom_error(BiddingError::BidCooldownActive),
+  );
+
+  fixture.warp(120).await;
+  fixture.place_bid(&alice, GAME_ID, BIDS[2], 4).await.unwrap();
+  fixture.place_bid(&bob, GAME_ID, BIDS[3], 5).await.unwrap();
+  assert_eq!(
+    fixture.place_bid(&alice, GAME_ID, BIDS[4], 6).await.unwrap_err(),
+    custom_error(BiddingError::BidLimitReached),
+  );
+}
@@ -0,0 +1,143 @@
+mod common;
+
+use common::{custom_error, default_rules, start_with_config, Fixture, INITIAL_BID};
+use programs::error::BiddingError;
+use programs::instructions::BiddingInstruction;
+use programs::state::{GameMode, GameRules, GameState, GameStatus, COMMITMENT_ACCOUNT_SIZE};
+use programs::utils::commitment_pda_seeds;
+use solana_program::{hash::hashv, instruction::AccountMeta, pubkey::Pubkey, rent::Rent, system_program};
+use solana_sdk::{
+  signature::{Keypair, Signer},
+  transaction::TransactionError,
+};
+
+const GAME_ID: u64 = 13;
+const SALT: [u8; 32] = [9; 32];
+
+fn commitment_key(fixture: &Fixture, bidder: &Pubkey) -> Pubkey {
+  commitment_pda_seeds(GAME_ID, bidder, &fixture.program_id).0
+}
+
+async fn sealed_game() -> (Fixture, Keypair) {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let rules = GameRules { reveal_window: 300, ..default_rules() };
+
+  fixture
+    .create_game_with(&creator, GAME_ID, INITIAL_BID, Some(rules), GameMode::Sealed, None, None)
+    .await
+    .unwrap();
+
+  (fixture, creator)
+}
+
+async fn commit_bid(fixture: &mut Fixture, bidder: &Keypair, amount: u64, deposit: u64) -> Result<(), TransactionError> {
+  let accounts = vec![
+    AccountMeta::new_readonly(system_program::id(), false),
+    AccountMeta::new(fixture.game_key(GAME_ID), false),
+    AccountMeta::new(bidder.pubkey(), true),
+    AccountMeta::new(commitment_key(fixture, &bidder.pubkey()), false),
+    AccountMeta::new(fixture.vault_key(GAME_ID), false),
+  ];
+  let instruction = BiddingInstruction::CommitBid {
+    game_id: GAME_ID,
+    commitment: hashv(&[&amount.to_le_bytes(), &SALT]).to_bytes(),
+    deposit,
+  };
+
+  fixture.process(&instruction, accounts, &[bidder]).await
+}
+
+async fn reveal_bid(
+  fixture: &mut Fixture,
+  bidder: &Keypair,
+  amount: u64,
+  salt: [u8; 32],
+  previous_leader: Option<&Pubkey>,
+) -> Result<(), TransactionError> {
+  let mut accounts = vec![
+    AccountMeta::new(fixture.game_key(GAME_ID), false),
+    AccountMeta::new(bidder.pubkey(), true),
+    AccountMeta::new(commitment_key(fixture, &bidder.pubkey()), false),
+    AccountMeta::new(fixture.vault_key(GAME_ID), false),
+  ];
+  if let Some(previous_leader) = previous_leader {
+    accounts.push(AccountMeta::new(commitment_key(fixture, previous_leader), false));
+    accounts.push(AccountMeta::new(*previous_leader, false));
+  }
+
+  fixture.process(&BiddingInstruction::RevealBid { game_id: GAME_ID, amount, salt }, accounts, &[bidder]).await
+}
+
+#[tokio::test]
+async fn higher_reveal_displaces_leader_and_wins() {
+  let commitment_rent = Rent::default().minimum_balance(COMMITMENT_ACCOUNT_SIZE);
+  let (mut fixture, creator) = sealed_game().await;
+  let leader = fixture.wallet().await;
+  let runner_up = fixture.wallet().await;
+
+  commit_bid(&mut fixture, &leader, 150_000_000, 200_000_000).await.unwrap();
+  commit_bid(&mut fixture, &runner_up, 130_000_000, 150_000_000).await.unwrap();
+
+  let game_state = fixture.state::<GameState>(fixture.game_key(GAME_ID)).await;
+  assert_eq!(game_state.escrowed_deposits, 350_000_000);
+
+  fixture.warp(601).await;
+  reveal_bid(&mut fixture, &runner_up, 130_000_000, SALT, None).await.unwrap();
+
+  let runner_up_lamports = fixture.lamports(runner_up.pubkey()).await;
+  reveal_bid(&mut fixture, &leader, 150_000_000, SALT, Some(&runner_up.pubkey())).await.unwrap();
+  assert_eq!(fixture.lamports(runner_up.pubkey()).await, runner_up_lamports + 150_000_000 + commitment_rent);
+  assert!(fixture.account(commitment_key(&fixture, &runner_up.pubkey())).await.is_none());
+
+  fixture.warp(300).await;
+  let winner = leader.pubkey();
+  let winner_lamports = fixture.lamports(winner).await;
+  let mut accounts = fixture.end_game_accounts(GAME_ID, &creator.pubkey());
+  accounts.extend([
+    AccountMeta::new(fixture.ledger_key(GAME_ID), false),
+    AccountMeta::new(winner, false),
+    AccountMeta::new(fixture.player_key(GAME_ID, &winner, 2), false),
+    AccountMeta::new(commitment_key(&fixture, &winner), false),
+  ]);
+  fixture.process(&BiddingInstruction::EndGame { game_id: GAME_ID }, accounts, &[&creator]).await.unwrap();
+
+  let pool = INITIAL_BID + 150_000_000;
+  let fee = pool / 10;
+  assert_eq!(fixture.lamports(winner).await, winner_lamports + 50_000_000 + commitment_rent + pool - fee);
+
+  let game_state = fixture.state::<GameState>(fixture.game_key(GAME_ID)).await;
+  assert_eq!(game_state.status, GameStatus::Ended);
+  assert_eq!(game_state.last_bidder, winner);
+  assert_eq!(game_state.escrowed_deposits, 0);
+}
+
+#[tokio::test]
+async fn reveal_rejects_wrong_salt_and_closed_windows() {
+  let (mut fixture, _) = sealed_game().await;
+  let bidder = fixture.wallet().await;
+
+  assert_eq!(
+    fixture.place_bid(&bidder, GAME_ID, 150_000_000, 2).await.unwrap_err(),
+    custom_error(BiddingError::InvalidGameMode),
+  );
+
+  commit_bid(&mut fixture, &bidder, 150_000_000, 200_000_000).await.unwrap();
+
+  assert_eq!(
+    reveal_bid(&mut fixture, &bidder, 150_000_000, SALT, None).await.unwrap_err(),
+    custom_error(BiddingError::RevealWindowClosed),
+  );
+
+  fixture.warp(601).await;
+  assert_eq!(
+    reveal_bid(&mut fixture, &bidder, 150_000_000, [0; 32], None).await.unwrap_err(),
+    custom_error(BiddingError::CommitmentMismatch),
+  );
+
+  fixture.warp(300).await;
+  assert_eq!(
+    reveal_bid(&mut fixture, &bidder, 150_000_000, SALT, None).await.unwrap_err(),
+    custom_error(BiddingError::RevealWindowClosed),
+  );
+}
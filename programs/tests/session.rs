@@ -0,0 +1,118 @@
+mod common;
+
+use common::{custom_error, start_with_config, Fixture};
+use programs::error::BiddingError;
+use programs::instructions::BiddingInstruction;
+use programs::state::{
+  GameState,
+  PlayerState,
+  Session,
+  BID_RECORD_SIZE,
+  LEDGER_ACCOUNT_SIZE,
+  PLAYER_ACCOUNT_SIZE,
+  STATS_ACCOUNT_SIZE,
+};
+use solana_program::{
+  instruction::{AccountMeta, InstructionError},
+  rent::Rent,
+};
+use solana_sdk::{
+  signature::{Keypair, Signer},
+  transaction::TransactionError,
+};
+
+const GAME_ID: u64 = 17;
+const BID: u64 = 110_000_000;
+
+struct Game {
+  fixture: Fixture,
+  owner: Keypair,
+  session_key: Keypair,
+}
+
+fn bid_charge() -> u64 {
+  let rent = Rent::default();
+  let ledger_growth = rent.minimum_balance(LEDGER_ACCOUNT_SIZE + 2 * BID_RECORD_SIZE)
+    - rent.minimum_balance(LEDGER_ACCOUNT_SIZE + BID_RECORD_SIZE);
+
+  BID + rent.minimum_balance(PLAYER_ACCOUNT_SIZE) + ledger_growth + rent.minimum_balance(STATS_ACCOUNT_SIZE)
+}
+
+async fn session_game(spending_cap: u64) -> Game {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let owner = fixture.wallet().await;
+  let session_key = Keypair::new();
+
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+  fixture.deposit(&owner, 1_000_000_000).await.unwrap();
+  let expires_at = fixture.now().await + 3_600;
+  fixture.register_session(&owner, &session_key.pubkey(), spending_cap, expires_at).await.unwrap();
+
+  Game { fixture, owner, session_key }
+}
+
+async fn session_bid(game: &mut Game) -> Result<(), TransactionError> {
+  let owner = game.owner.pubkey();
+  let fixture = &mut game.fixture;
+  let mut accounts = fixture.place_bid_accounts(GAME_ID, &owner, &owner, 2);
+  accounts[3] = AccountMeta::new_readonly(owner, false);
+  accounts.extend([
+    AccountMeta::new(fixture.balance_key(&owner), false),
+    AccountMeta::new(fixture.session_key(&owner), false),
+    AccountMeta::new_readonly(game.session_key.pubkey(), true),
+  ]);
+
+  let instruction = BiddingInstruction::PlaceBid { bid_amount: BID, bid_count: 2 };
+  fixture.process(&instruction, accounts, &[&game.session_key]).await
+}
+
+#[tokio::test]
+async fn place_bid_accepts_session_key_and_pays_from_owner_balance() {
+  let mut game = session_game(bid_charge()).await;
+  let owner = game.owner.pubkey();
+  let owner_lamports = game.fixture.lamports(owner).await;
+  let balance_lamports = game.fixture.lamports(game.fixture.balance_key(&owner)).await;
+
+  session_bid(&mut game).await.unwrap();
+
+  let fixture = &mut game.fixture;
+  let game_state = fixture.state::<GameState>(fixture.game_key(GAME_ID)).await;
+  assert_eq!((game_state.last_bidder, game_state.total_bids), (owner, 2));
+
+  let player = fixture.state::<PlayerState>(fixture.player_key(GAME_ID, &owner, 2)).await;
+  assert_eq!((player.bidder, player.payer), (owner, fixture.balance_key(&owner)));
+
+  let session = fixture.state::<Session>(fixture.session_key(&owner)).await;
+  assert_eq!(session.spent, bid_charge());
+
+  assert_eq!(fixture.lamports(owner).await, owner_lamports);
+  assert_eq!(fixture.lamports(fixture.balance_key(&owner)).await, balance_lamports - bid_charge());
+}
+
+#[tokio::test]
+async fn session_spending_cap_counts_account_rent() {
+  let mut game = session_game(BID).await;
+
+  assert_eq!(
+    session_bid(&mut game).await.unwrap_err(),
+    custom_error(BiddingError::SessionCapExceeded),
+  );
+}
+
+#[tokio::test]
+async fn place_bid_without_wallet_signature_requires_session() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let bidder = fixture.wallet().await;
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+
+  let mut accounts = fixture.place_bid_accounts(GAME_ID, &bidder.pubkey(), &bidder.pubkey(), 2);
+  accounts[3] = AccountMeta::new(bidder.pubkey(), false);
+  let instruction = BiddingInstruction::PlaceBid { bid_amount: BID, bid_count: 2 };
+
+  assert_eq!(
+    fixture.process(&instruction, accounts, &[]).await.unwrap_err(),
+    TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys),
+  );
+}
@@ -0,0 +1,121 @@
+mod common;
+
+use common::{custom_error, start_with_config, Fixture, INITIAL_BID};
+use programs::error::BiddingError;
+use programs::state::{GameState, GameStatus, PlayerState, Settlement};
+use solana_program::{instruction::AccountMeta, system_instruction};
+use solana_sdk::signature::{Keypair, Signer};
+
+const GAME_ID: u64 = 2;
+const BIDS: [u64; 4] = [110_000_000, 121_000_000, 134_000_000, 148_000_000];
+const ROYALTY: u64 = 134_000_000;
+
+struct Game {
+  fixture: Fixture,
+  creator: Keypair,
+  bidders: Vec<Keypair>,
+}
+
+async fn contested_game() -> Game {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+
+  let mut bidders = Vec::new();
+  for (index, amount) in BIDS.iter().enumerate() {
+    let bidder = fixture.wallet().await;
+    fixture.place_bid(&bidder, GAME_ID, *amount, index as u64 + 2).await.unwrap();
+    bidders.push(bidder);
+  }
+  fixture.warp(601).await;
+
+  Game { fixture, creator, bidders }
+}
+
+async fn end_contested_game(game: &mut Game) {
+  let winner = game.bidders[3].pubkey();
+  game.fixture.end_game(&game.creator, GAME_ID, &winner, 5).await.unwrap();
+}
+
+fn royalty_share(weight: u64, amount: u64) -> u64 {
+  let eligible_amount = (INITIAL_BID + BIDS[0]) as u128;
+  (weight as u128 * amount as u128 * ROYALTY as u128 / (3 * eligible_amount)) as u64
+}
+
+#[tokio::test]
+async fn settle_batch_weights_royalties_across_batches() {
+  let mut game = contested_game().await;
+  end_contested_game(&mut game).await;
+
+  let creator = game.creator.pubkey();
+  let first = game.bidders[0].pubkey();
+  let winner = game.bidders[3].pubkey();
+  let fixture = &mut game.fixture;
+
+  let settlement = fixture.state::<Settlement>(fixture.settlement_key(GAME_ID)).await;
+  assert_eq!(settlement.eligible_bids, 2);
+  assert_eq!(settlement.royalty_amount, ROYALTY);
+
+  let creator_player = fixture.player_key(GAME_ID, &creator, 1);
+  let first_player = fixture.player_key(GAME_ID, &first, 2);
+  let winner_player = fixture.player_key(GAME_ID, &winner, 5);
+
+  fixture.settle_batch(GAME_ID, 0, 1, vec![AccountMeta::new(creator_player, false)]).await.unwrap();
+
+  let settlement = fixture.state::<Settlement>(fixture.settlement_key(GAME_ID)).await;
+  assert_eq!(settlement.next_index, 1);
+
+  let winner_lamports = fixture.lamports(winner).await;
+  let accounts = vec![
+    AccountMeta::new(first_player, false),
+    AccountMeta::new(winner, false),
+    AccountMeta::new(winner_player, false),
+    AccountMeta::new(creator, false),
+  ];
+  fixture.settle_batch(GAME_ID, 1, 1, accounts).await.unwrap();
+
+  let creator_royalty = royalty_share(2, INITIAL_BID);
+  let first_royalty = royalty_share(1, BIDS[0]);
+  assert_eq!(fixture.state::<PlayerState>(creator_player).await.royalty_earned, creator_royalty);
+  assert_eq!(fixture.state::<PlayerState>(first_player).await.royalty_earned, first_royalty);
+
+  let pool = INITIAL_BID + BIDS.iter().sum::<u64>();
+  let fee = (BIDS[1] + BIDS[2] + BIDS[3]) / 10;
+  let entitled = INITIAL_BID + BIDS[0] + creator_royalty + first_royalty;
+  assert_eq!(fixture.lamports(winner).await, winner_lamports + pool - fee - entitled);
+
+  let game_state = fixture.state::<GameState>(fixture.game_key(GAME_ID)).await;
+  assert_eq!(game_state.status, GameStatus::Ended);
+  assert!(fixture.account(fixture.settlement_key(GAME_ID)).await.is_none());
+}
+
+#[tokio::test]
+async fn settle_batch_rejects_batches_out_of_order() {
+  let mut game = contested_game().await;
+  end_contested_game(&mut game).await;
+
+  let first = game.bidders[0].pubkey();
+  let fixture = &mut game.fixture;
+  let first_player = fixture.player_key(GAME_ID, &first, 2);
+
+  assert_eq!(
+    fixture.settle_batch(GAME_ID, 1, 1, vec![AccountMeta::new(first_player, false)]).await.unwrap_err(),
+    custom_error(BiddingError::SettlementOutOfOrder),
+  );
+}
+
+#[tokio::test]
+async fn end_game_opens_settlement_at_a_prefunded_address() {
+  let mut game = contested_game().await;
+  let settlement_key = game.fixture.settlement_key(GAME_ID);
+  let payer = game.fixture.context.payer.pubkey();
+
+  let transfer = system_instruction::transfer(&payer, &settlement_key, 1_000_000);
+  game.fixture.send(&[transfer], &[]).await.unwrap();
+
+  end_contested_game(&mut game).await;
+
+  let settlement = game.fixture.state::<Settlement>(settlement_key).await;
+  assert_eq!(settlement.eligible_bids, 2);
+  assert_eq!(settlement.payer, game.creator.pubkey());
+}
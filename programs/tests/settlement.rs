@@ -0,0 +1,111 @@
+mod common;
+
+use common::{config_params, custom_error, start, start_with_config, Fixture, INITIAL_BID};
+use programs::error::BiddingError;
+use programs::instructions::ConfigParams;
+use programs::state::{GameState, GameStatus, PlayerState};
+use solana_program::instruction::AccountMeta;
+use solana_sdk::signature::{Keypair, Signer};
+
+const GAME_ID: u64 = 11;
+const BIDS: [u64; 3] = [110_000_000, 121_000_000, 134_000_000];
+
+struct Game {
+  fixture: Fixture,
+  creator: Keypair,
+  bidders: Vec<Keypair>,
+}
+
+async fn settling_game(params: ConfigParams) -> Game {
+  let mut fixture = start().await;
+  fixture.initialize_config(params).await.unwrap();
+  let creator = fixture.wallet().await;
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+
+  let mut bidders = Vec::new();
+  for (index, amount) in BIDS.iter().enumerate() {
+    let bidder = fixture.wallet().await;
+    fixture.place_bid(&bidder, GAME_ID, *amount, index as u64 + 2).await.unwrap();
+    bidders.push(bidder);
+  }
+
+  fixture.warp(601).await;
+  let winner = bidders[2].pubkey();
+  fixture.end_game(&creator, GAME_ID, &winner, 4).await.unwrap();
+
+  Game { fixture, creator, bidders }
+}
+
+fn settle_accounts(game: &Game) -> Vec<AccountMeta> {
+  let creator = game.creator.pubkey();
+  let winner = game.bidders[2].pubkey();
+
+  vec![
+    AccountMeta::new(game.fixture.player_key(GAME_ID, &creator, 1), false),
+    AccountMeta::new(winner, false),
+    AccountMeta::new(game.fixture.player_key(GAME_ID, &winner, 4), false),
+    AccountMeta::new(creator, false),
+  ]
+}
+
+#[tokio::test]
+async fn initialize_config_rejects_fee_above_half() {
+  let mut fixture = start().await;
+
+  let params = ConfigParams { platform_fee_percentage: 51, ..config_params() };
+  assert_eq!(
+    fixture.initialize_config(params).await.unwrap_err(),
+    custom_error(BiddingError::InvalidConfig),
+  );
+}
+
+#[tokio::test]
+async fn initialize_config_rejects_royalty_window_below_three() {
+  let mut fixture = start().await;
+
+  let mut params = config_params();
+  params.min_rules.royalty_window = 2;
+  assert_eq!(
+    fixture.initialize_config(params).await.unwrap_err(),
+    custom_error(BiddingError::InvalidConfig),
+  );
+}
+
+#[tokio::test]
+async fn settle_batch_pays_winner_remainder_at_maximum_fee() {
+  let params = ConfigParams { platform_fee_percentage: 50, ..config_params() };
+  let mut game = settling_game(params).await;
+  let winner = game.bidders[2].pubkey();
+  let pool = INITIAL_BID + BIDS.iter().sum::<u64>();
+  let fee = BIDS.iter().sum::<u64>() / 2;
+  let entitled = INITIAL_BID + BIDS[1];
+  let winner_lamports = game.fixture.lamports(winner).await;
+
+  let accounts = settle_accounts(&game);
+  game.fixture.settle_batch(GAME_ID, 0, 1, accounts).await.unwrap();
+
+  let fixture = &mut game.fixture;
+  let game_state = fixture.state::<GameState>(fixture.game_key(GAME_ID)).await;
+  assert_eq!(game_state.status, GameStatus::Ended);
+  assert_eq!(fixture.lamports(winner).await, winner_lamports + pool - fee - entitled);
+  assert!(fixture.account(fixture.settlement_key(GAME_ID)).await.is_none());
+
+  let creator_player = fixture.player_key(GAME_ID, &game.creator.pubkey(), 1);
+  let player = fixture.state::<PlayerState>(creator_player).await;
+  assert!(player.safe);
+  assert_eq!(player.royalty_earned, BIDS[1]);
+}
+
+#[tokio::test]
+async fn end_game_rejects_game_within_timeout() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let bidder = fixture.wallet().await;
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+  fixture.place_bid(&bidder, GAME_ID, BIDS[0], 2).await.unwrap();
+
+  assert_eq!(
+    fixture.end_game(&creator, GAME_ID, &bidder.pubkey(), 2).await.unwrap_err(),
+    custom_error(BiddingError::GameStillActive),
+  );
+}
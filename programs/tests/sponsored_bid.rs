@@ -0,0 +1,57 @@
+mod common;
+
+use common::{start_with_config, INITIAL_BID};
+use programs::instructions::BiddingInstruction;
+use programs::state::{GameState, PlayerState, PlayerStats};
+use solana_program::instruction::{AccountMeta, InstructionError};
+use solana_sdk::{
+  signature::{Keypair, Signer},
+  transaction::TransactionError,
+};
+
+const GAME_ID: u64 = 18;
+const BID: u64 = 110_000_000;
+
+#[tokio::test]
+async fn sponsored_bid_credits_beneficiary_and_charges_sponsor() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let sponsor = fixture.wallet().await;
+  let beneficiary = Keypair::new();
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+
+  let sponsor_lamports = fixture.lamports(sponsor.pubkey()).await;
+  fixture.sponsor_bid(&sponsor, &beneficiary, GAME_ID, BID, 2).await.unwrap();
+
+  let game_state = fixture.state::<GameState>(fixture.game_key(GAME_ID)).await;
+  assert_eq!(game_state.last_bidder, beneficiary.pubkey());
+  assert_eq!(game_state.prize_pool, INITIAL_BID + BID);
+
+  let player_key = fixture.player_key(GAME_ID, &beneficiary.pubkey(), 2);
+  let player = fixture.state::<PlayerState>(player_key).await;
+  assert_eq!((player.bidder, player.payer), (beneficiary.pubkey(), sponsor.pubkey()));
+
+  let stats = fixture.state::<PlayerStats>(fixture.stats_key(GAME_ID, &beneficiary.pubkey())).await;
+  assert_eq!((stats.player, stats.payer, stats.bid_count), (beneficiary.pubkey(), sponsor.pubkey(), 1));
+
+  assert!(fixture.lamports(sponsor.pubkey()).await < sponsor_lamports - BID);
+  assert_eq!(fixture.lamports(beneficiary.pubkey()).await, 0);
+}
+
+#[tokio::test]
+async fn sponsored_bid_requires_beneficiary_signature() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let sponsor = fixture.wallet().await;
+  let beneficiary = Keypair::new().pubkey();
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+
+  let mut accounts = fixture.place_bid_accounts(GAME_ID, &sponsor.pubkey(), &beneficiary, 2);
+  accounts.push(AccountMeta::new_readonly(beneficiary, false));
+  let instruction = BiddingInstruction::PlaceBid { bid_amount: BID, bid_count: 2 };
+
+  assert_eq!(
+    fixture.process(&instruction, accounts, &[&sponsor]).await.unwrap_err(),
+    TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature),
+  );
+}
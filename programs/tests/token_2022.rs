@@ -0,0 +1,93 @@
+mod common;
+
+use common::{custom_error, start_with_config, Fixture, INITIAL_BID};
+use programs::error::BiddingError;
+use programs::state::GameState;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const GAME_ID: u64 = 12;
+const TRANSFER_FEE_BPS: u16 = 100;
+const SUPPLY: u64 = 1_000_000_000;
+
+struct FeeGame {
+  fixture: Fixture,
+  creator: Keypair,
+  mint: Pubkey,
+}
+
+fn after_transfer_fee(amount: u64) -> u64 {
+  amount - amount.div_ceil(10_000 / TRANSFER_FEE_BPS as u64)
+}
+
+async fn fee_game() -> FeeGame {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let mint = fixture.create_mint(&spl_token_2022::id(), Some(TRANSFER_FEE_BPS)).await;
+  let creator_tokens = funded_token_account(&mut fixture, &mint, &creator.pubkey()).await;
+
+  fixture.create_token_game(&creator, GAME_ID, &spl_token_2022::id(), &mint, &creator_tokens).await.unwrap();
+
+  FeeGame { fixture, creator, mint }
+}
+
+async fn funded_token_account(fixture: &mut Fixture, mint: &Pubkey, owner: &Pubkey) -> Pubkey {
+  let account = fixture.create_token_account(&spl_token_2022::id(), mint, owner).await;
+  fixture.mint_to(&spl_token_2022::id(), mint, &account, SUPPLY).await;
+  account
+}
+
+#[tokio::test]
+async fn transfer_fee_game_records_and_pays_received_amounts() {
+  let FeeGame { mut fixture, creator, mint } = fee_game().await;
+  let token_program = spl_token_2022::id();
+  let bidder = fixture.wallet().await;
+  let bidder_tokens = funded_token_account(&mut fixture, &mint, &bidder.pubkey()).await;
+  let treasury_key = fixture.treasury_key();
+  let treasury_tokens = fixture.create_token_account(&token_program, &mint, &treasury_key).await;
+  let vault_key = fixture.vault_key(GAME_ID);
+
+  let initial_bid = after_transfer_fee(INITIAL_BID);
+  let game_state = fixture.state::<GameState>(fixture.game_key(GAME_ID)).await;
+  assert_eq!(game_state.initial_bid_amount, initial_bid);
+  assert_eq!(fixture.token_balance(vault_key).await, initial_bid);
+
+  fixture.place_token_bid(&bidder, GAME_ID, 120_000_000, 2, &token_program, &mint, &bidder_tokens).await.unwrap();
+
+  let bid = after_transfer_fee(120_000_000);
+  let game_state = fixture.state::<GameState>(fixture.game_key(GAME_ID)).await;
+  assert_eq!(game_state.highest_bid, bid);
+  assert_eq!(game_state.prize_pool, initial_bid + bid);
+  assert_eq!(fixture.token_balance(vault_key).await, initial_bid + bid);
+
+  fixture.warp(601).await;
+  let bidder_balance = fixture.token_balance(bidder_tokens).await;
+  fixture
+    .end_token_game(&creator, GAME_ID, &token_program, &mint, &treasury_tokens, &bidder.pubkey(), &bidder_tokens, 2)
+    .await
+    .unwrap();
+
+  let fee = (initial_bid + bid) / 10;
+  assert_eq!(fixture.token_balance(treasury_tokens).await, after_transfer_fee(fee));
+  assert_eq!(
+    fixture.token_balance(bidder_tokens).await,
+    bidder_balance + after_transfer_fee(initial_bid + bid - fee),
+  );
+  assert_eq!(fixture.token_balance(vault_key).await, 0);
+}
+
+#[tokio::test]
+async fn transfer_fee_game_checks_the_bid_net_of_fees() {
+  let FeeGame { mut fixture, mint, .. } = fee_game().await;
+  let token_program = spl_token_2022::id();
+  let bidder = fixture.wallet().await;
+  let bidder_tokens = funded_token_account(&mut fixture, &mint, &bidder.pubkey()).await;
+
+  assert_eq!(
+    fixture
+      .place_token_bid(&bidder, GAME_ID, 109_000_000, 2, &token_program, &mint, &bidder_tokens)
+      .await
+      .unwrap_err(),
+    custom_error(BiddingError::InsufficientBidAmount),
+  );
+}
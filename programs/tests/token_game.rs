@@ -0,0 +1,82 @@
+mod common;
+
+use common::{custom_error, start_with_config, Fixture, INITIAL_BID, SPL_TOKEN_ID};
+use programs::error::BiddingError;
+use programs::state::{GameState, GameStatus};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+const GAME_ID: u64 = 11;
+const BID: u64 = 110_000_000;
+const SUPPLY: u64 = 1_000_000_000;
+
+struct TokenGame {
+  fixture: Fixture,
+  creator: Keypair,
+  mint: Pubkey,
+}
+
+async fn token_game() -> TokenGame {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let mint = fixture.create_mint(&SPL_TOKEN_ID, None).await;
+  let creator_tokens = funded_token_account(&mut fixture, &mint, &creator.pubkey()).await;
+
+  fixture.create_token_game(&creator, GAME_ID, &SPL_TOKEN_ID, &mint, &creator_tokens).await.unwrap();
+
+  TokenGame { fixture, creator, mint }
+}
+
+async fn funded_token_account(fixture: &mut Fixture, mint: &Pubkey, owner: &Pubkey) -> Pubkey {
+  let account = fixture.create_token_account(&SPL_TOKEN_ID, mint, owner).await;
+  fixture.mint_to(&SPL_TOKEN_ID, mint, &account, SUPPLY).await;
+  account
+}
+
+#[tokio::test]
+async fn token_game_pays_winner_and_treasury_in_tokens() {
+  let TokenGame { mut fixture, creator, mint } = token_game().await;
+  let bidder = fixture.wallet().await;
+  let bidder_tokens = funded_token_account(&mut fixture, &mint, &bidder.pubkey()).await;
+  let treasury_key = fixture.treasury_key();
+  let treasury_tokens = fixture.create_token_account(&SPL_TOKEN_ID, &mint, &treasury_key).await;
+  let vault_key = fixture.vault_key(GAME_ID);
+
+  let game_state = fixture.state::<GameState>(fixture.game_key(GAME_ID)).await;
+  assert_eq!(game_state.mint, mint);
+  assert_eq!(fixture.token_balance(vault_key).await, INITIAL_BID);
+
+  fixture.place_token_bid(&bidder, GAME_ID, BID, 2, &SPL_TOKEN_ID, &mint, &bidder_tokens).await.unwrap();
+  assert_eq!(fixture.token_balance(vault_key).await, INITIAL_BID + BID);
+  assert_eq!(fixture.token_balance(bidder_tokens).await, SUPPLY - BID);
+
+  fixture.warp(601).await;
+  fixture
+    .end_token_game(&creator, GAME_ID, &SPL_TOKEN_ID, &mint, &treasury_tokens, &bidder.pubkey(), &bidder_tokens, 2)
+    .await
+    .unwrap();
+
+  let fee = (INITIAL_BID + BID) / 10;
+  assert_eq!(fixture.token_balance(treasury_tokens).await, fee);
+  assert_eq!(fixture.token_balance(bidder_tokens).await, SUPPLY - BID + INITIAL_BID + BID - fee);
+  assert_eq!(fixture.token_balance(vault_key).await, 0);
+
+  let game_state = fixture.state::<GameState>(fixture.game_key(GAME_ID)).await;
+  assert_eq!(game_state.status, GameStatus::Ended);
+}
+
+#[tokio::test]
+async fn token_game_rejects_bids_in_another_mint() {
+  let TokenGame { mut fixture, .. } = token_game().await;
+  let bidder = fixture.wallet().await;
+  let other_mint = fixture.create_mint(&SPL_TOKEN_ID, None).await;
+  let other_tokens = funded_token_account(&mut fixture, &other_mint, &bidder.pubkey()).await;
+
+  assert_eq!(
+    fixture
+      .place_token_bid(&bidder, GAME_ID, BID, 2, &SPL_TOKEN_ID, &other_mint, &other_tokens)
+      .await
+      .unwrap_err(),
+    custom_error(BiddingError::InvalidMint),
+  );
+}
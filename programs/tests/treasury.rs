@@ -0,0 +1,72 @@
+mod common;
+
+use common::{custom_error, start_with_config, Fixture, INITIAL_BID};
+use programs::error::BiddingError;
+use programs::instructions::BiddingInstruction;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_sdk::{
+  signature::{Keypair, Signer},
+  transaction::TransactionError,
+};
+
+const GAME_ID: u64 = 9;
+const BID: u64 = 110_000_000;
+const FEE: u64 = (INITIAL_BID + BID) / 10;
+
+async fn ended_game() -> Fixture {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let bidder = fixture.wallet().await;
+
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+  fixture.place_bid(&bidder, GAME_ID, BID, 2).await.unwrap();
+  fixture.warp(601).await;
+  fixture.end_game(&creator, GAME_ID, &bidder.pubkey(), 2).await.unwrap();
+
+  fixture
+}
+
+async fn withdraw_fees(
+  fixture: &mut Fixture,
+  authority: &Keypair,
+  destination: Pubkey,
+  amount: u64,
+) -> Result<(), TransactionError> {
+  let accounts = vec![
+    AccountMeta::new_readonly(fixture.config_key(), false),
+    AccountMeta::new_readonly(authority.pubkey(), true),
+    AccountMeta::new(fixture.treasury_key(), false),
+    AccountMeta::new(destination, false),
+  ];
+
+  fixture.process(&BiddingInstruction::WithdrawFees { amount }, accounts, &[authority]).await
+}
+
+#[tokio::test]
+async fn withdraw_fees_pays_collected_fees_to_authority_destination() {
+  let mut fixture = ended_game().await;
+  let authority = fixture.authority.insecure_clone();
+  let destination = Pubkey::new_unique();
+  let treasury_lamports = fixture.lamports(fixture.treasury_key()).await;
+
+  withdraw_fees(&mut fixture, &authority, destination, FEE).await.unwrap();
+
+  assert_eq!(fixture.lamports(destination).await, FEE);
+  assert_eq!(fixture.lamports(fixture.treasury_key()).await, treasury_lamports - FEE);
+
+  assert_eq!(
+    withdraw_fees(&mut fixture, &authority, destination, 1).await.unwrap_err(),
+    custom_error(BiddingError::InsufficientFunds),
+  );
+}
+
+#[tokio::test]
+async fn withdraw_fees_rejects_other_signers() {
+  let mut fixture = ended_game().await;
+  let impostor = fixture.wallet().await;
+
+  assert_eq!(
+    withdraw_fees(&mut fixture, &impostor, impostor.pubkey(), FEE).await.unwrap_err(),
+    custom_error(BiddingError::InvalidAuthority),
+  );
+}
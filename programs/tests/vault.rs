@@ -0,0 +1,51 @@
+mod common;
+
+use common::{custom_error, start_with_config, INITIAL_BID};
+use programs::error::BiddingError;
+use programs::instructions::BiddingInstruction;
+use solana_program::{instruction::AccountMeta, rent::Rent};
+use solana_sdk::signature::Signer;
+
+const GAME_ID: u64 = 8;
+const OTHER_GAME_ID: u64 = 9;
+const BID: u64 = 110_000_000;
+
+#[tokio::test]
+async fn bids_are_held_in_the_game_vault() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let bidder = fixture.wallet().await;
+
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+
+  let vault_key = fixture.vault_key(GAME_ID);
+  let game_key = fixture.game_key(GAME_ID);
+  let vault = fixture.account(vault_key).await.unwrap();
+  assert_eq!(vault.owner, fixture.program_id);
+  assert_eq!(vault.lamports, Rent::default().minimum_balance(0) + INITIAL_BID);
+
+  let game_lamports = fixture.lamports(game_key).await;
+  fixture.place_bid(&bidder, GAME_ID, BID, 2).await.unwrap();
+
+  assert_eq!(fixture.lamports(vault_key).await, vault.lamports + BID);
+  assert_eq!(fixture.lamports(game_key).await, game_lamports);
+}
+
+#[tokio::test]
+async fn place_bid_rejects_another_games_vault() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let bidder = fixture.wallet().await;
+
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+  fixture.create_game(&creator, OTHER_GAME_ID).await.unwrap();
+
+  let mut accounts = fixture.place_bid_accounts(GAME_ID, &bidder.pubkey(), &bidder.pubkey(), 2);
+  accounts[6] = AccountMeta::new(fixture.vault_key(OTHER_GAME_ID), false);
+  let instruction = BiddingInstruction::PlaceBid { bid_amount: BID, bid_count: 2 };
+
+  assert_eq!(
+    fixture.process(&instruction, accounts, &[&bidder]).await.unwrap_err(),
+    custom_error(BiddingError::InvalidVaultAccount),
+  );
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use common::{start_with_config, INITIAL_BID};
+use programs::layout::HEADER_LEN;
+use programs::state::{GameState, BID_RECORD_SIZE, LEDGER_ACCOUNT_SIZE};
+use programs::zero_copy::{BidView, GameStateView};
+use solana_program::instruction::InstructionError;
+use solana_sdk::{signature::Signer, transaction::TransactionError};
+
+const GAME_ID: u64 = 23;
+const BIDS: [u64; 2] = [110_000_000, 121_000_000];
+
+#[tokio::test]
+async fn bids_update_game_view_and_ledger_records_in_place() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let bidders = [fixture.wallet().await, fixture.wallet().await];
+
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+  for (index, bidder) in bidders.iter().enumerate() {
+    fixture.place_bid(bidder, GAME_ID, BIDS[index], index as u64 + 2).await.unwrap();
+  }
+
+  let game_account = fixture.account(fixture.game_key(GAME_ID)).await.unwrap();
+  let view = bytemuck::from_bytes::<GameStateView>(&game_account.data[HEADER_LEN..]);
+  let game_state = fixture.state::<GameState>(fixture.game_key(GAME_ID)).await;
+
+  assert_eq!(view.highest_bid.get(), BIDS[1]);
+  assert_eq!(view.total_bids.get(), 3);
+  assert_eq!(view.last_bidder, bidders[1].pubkey());
+  assert_eq!(view.prize_pool.get(), INITIAL_BID + BIDS[0] + BIDS[1]);
+  assert_eq!(view.deadline.get(), game_state.deadline);
+  assert_eq!(view.ends_at.get(), game_state.ends_at);
+
+  let ledger = fixture.account(fixture.ledger_key(GAME_ID)).await.unwrap();
+  assert_eq!(ledger.data.len(), LEDGER_ACCOUNT_SIZE + 3 * BID_RECORD_SIZE);
+
+  let records: Vec<&BidView> = ledger.data[LEDGER_ACCOUNT_SIZE..]
+    .chunks(BID_RECORD_SIZE)
+    .map(bytemuck::from_bytes)
+    .collect();
+  assert_eq!(records[0].bidder, creator.pubkey());
+  assert_eq!(records[0].amount.get(), INITIAL_BID);
+  for (index, bidder) in bidders.iter().enumerate() {
+    assert_eq!(records[index + 1].bidder, bidder.pubkey());
+    assert_eq!(records[index + 1].amount.get(), BIDS[index]);
+  }
+}
+
+#[tokio::test]
+async fn truncated_game_account_is_rejected() {
+  let mut fixture = start_with_config().await;
+  let creator = fixture.wallet().await;
+  let bidder = fixture.wallet().await;
+  let game_key = fixture.game_key(GAME_ID);
+
+  fixture.create_game(&creator, GAME_ID).await.unwrap();
+
+  let mut account = fixture.account(game_key).await.unwrap();
+  account.data.pop();
+  fixture.context.set_account(&game_key, &account.into());
+
+  assert_eq!(
+    fixture.place_bid(&bidder, GAME_ID, BIDS[0], 2).await.unwrap_err(),
+    TransactionError::InstructionError(0, InstructionError::InvalidAccountData),
+  );
+}